@@ -0,0 +1,223 @@
+//! Registry of known good answers
+//!
+//! Answers are stored in `answers.toml`, one table per day and input:
+//!
+//! ```toml
+//! [day-16.input]
+//! part1 = 7498
+//! part2 = "7846"
+//! ```
+use anyhow::{bail, ensure, Context};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    input::{workspace_root, Inputs},
+    Solution,
+};
+
+/// Environment variable overriding the location of the registry
+pub const ANSWERS_ENV: &str = "AOC_ANSWERS";
+/// File name of the registry in the workspace root
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key {
+    pub day: u8,
+    pub part: u8,
+    pub id: String,
+}
+
+/// Known answers keyed by day, part and input id
+#[derive(Debug, Clone, Default)]
+pub struct Answers {
+    answers: BTreeMap<Key, String>,
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> anyhow::Result<Self> {
+        let table: toml::Table = input.parse()?;
+        let mut answers = BTreeMap::new();
+        for (day_key, ids) in table {
+            let day = day_key
+                .strip_prefix("day-")
+                .and_then(|day| day.parse::<u8>().ok())
+                .with_context(|| format!("`{day_key}` should look like `day-01`"))?;
+            let Some(ids) = ids.as_table() else {
+                bail!("`{day_key}` should contain one table per input");
+            };
+            for (id, parts) in ids {
+                let Some(parts) = parts.as_table() else {
+                    bail!("`{day_key}.{id}` should contain `part1` and/or `part2`");
+                };
+                for (part_key, answer) in parts {
+                    let part = match part_key.as_str() {
+                        "part1" => 1,
+                        "part2" => 2,
+                        _ => bail!("unknown key `{day_key}.{id}.{part_key}`"),
+                    };
+                    let answer = match answer {
+                        toml::Value::String(answer) => answer.clone(),
+                        toml::Value::Integer(answer) => answer.to_string(),
+                        _ => bail!("`{day_key}.{id}.{part_key}` should be a string or integer"),
+                    };
+                    let key = Key {
+                        day,
+                        part,
+                        id: id.clone(),
+                    };
+                    answers.insert(key, answer);
+                }
+            }
+        }
+        Ok(Self { answers })
+    }
+
+    /// Reads the registry, a missing file is an empty registry
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(input) => Self::parse(&input).with_context(|| format!("parsing {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("reading {}", path.display())),
+        }
+    }
+
+    /// Uses `AOC_ANSWERS` or falls back to `answers.toml` in the workspace root
+    pub fn from_env() -> anyhow::Result<Self> {
+        Self::load(&path_from_env())
+    }
+
+    pub fn get(&self, day: u8, part: u8, id: &str) -> Option<&str> {
+        let key = Key {
+            day,
+            part,
+            id: id.to_string(),
+        };
+        self.answers.get(&key).map(String::as_str)
+    }
+
+    /// Input ids which have an answer for at least one part of `day`
+    pub fn ids(&self, day: u8) -> Vec<&str> {
+        let mut ids: Vec<&str> = self
+            .answers
+            .keys()
+            .filter(|key| key.day == day)
+            .map(|key| key.id.as_str())
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Key, &str)> {
+        self.answers
+            .iter()
+            .map(|(key, answer)| (key, answer.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+pub fn path_from_env() -> PathBuf {
+    match std::env::var_os(ANSWERS_ENV) {
+        Some(path) => PathBuf::from(path),
+        None => workspace_root().join(ANSWERS_FILE),
+    }
+}
+
+/// Checks `S` against every registered answer whose input exists
+///
+/// Meant for tests: missing inputs are skipped (and said so) instead of failing.
+pub fn check<S: Solution>() -> anyhow::Result<()> {
+    let answers = Answers::from_env()?;
+    let inputs = Inputs::from_env();
+    let mut registered = false;
+    for id in answers.ids(S::DAY) {
+        let Some(expected) = answers.get(S::DAY, S::PART, id) else {
+            continue;
+        };
+        registered = true;
+        let Some(input) = inputs.try_load(S::DAY, id)? else {
+            eprintln!(
+                "skipping: no input at {}",
+                inputs.path(S::DAY, id).display()
+            );
+            continue;
+        };
+        let answer = S::process(&input)
+            .with_context(|| format!("input `{id}`"))?
+            .to_string();
+        ensure!(
+            answer == expected,
+            "day {} part {} input `{}`: expected {}, got {}",
+            S::DAY,
+            S::PART,
+            id,
+            expected,
+            answer
+        );
+    }
+    if !registered {
+        eprintln!(
+            "skipping: no answer registered for day {} part {}",
+            S::DAY,
+            S::PART
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            r#"
+[day-16.input]
+part1 = 7498
+part2 = "7846"
+
+[day-16.other]
+part2 = "1"
+
+[day-03.input]
+part1 = 4361
+"#,
+        )
+        .unwrap();
+        assert_eq!(4, answers.len());
+        assert_eq!(Some("7498"), answers.get(16, 1, "input"));
+        assert_eq!(Some("7846"), answers.get(16, 2, "input"));
+        assert_eq!(None, answers.get(16, 1, "other"));
+        assert_eq!(vec!["input", "other"], answers.ids(16));
+        assert_eq!(vec!["input"], answers.ids(3));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for input in [
+            "[day16.input]\npart1 = 1",
+            "[day-16.input]\npart3 = 1",
+            "[day-16.input]\npart1 = 1.5",
+            "[day-16]\npart1 = 1",
+        ] {
+            assert!(Answers::parse(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let path = std::env::temp_dir().join("aoc-core-missing-answers.toml");
+        assert!(Answers::load(&path).unwrap().is_empty());
+    }
+}
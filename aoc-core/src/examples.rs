@@ -0,0 +1,248 @@
+//! Examples of a saved puzzle description
+//!
+//! [`extract`] pulls the `<pre><code>` blocks and the emphasised answers out of
+//! the HTML of a puzzle page, [`write`] stores them as fixtures
+//! `day-XX/examples/partN/K.txt`, the expected answer next to the block it
+//! belongs to as `K.answer`. Tests pick them up with rstest:
+//!
+//! ```ignore
+//! #[rstest]
+//! fn test_examples(#[files("examples/part1/*.txt")] path: PathBuf) -> anyhow::Result<()> {
+//!     aoc_core::examples::check::<Part1>(&path)
+//! }
+//! ```
+use crate::Solution;
+use anyhow::{ensure, Context};
+use std::path::{Path, PathBuf};
+
+/// One example block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    /// Expected answer, only known for the block the text gives one for
+    pub answer: Option<String>,
+}
+
+/// Examples of one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Part {
+    pub part: u8,
+    pub examples: Vec<Example>,
+}
+
+/// Text of every `<open>...<close>` in `html` together with its byte range
+fn elements<'a>(html: &'a str, open: &str, close: &str) -> Vec<(usize, usize, &'a str)> {
+    let mut found = Vec::new();
+    let mut rest = 0;
+    while let Some(start) = html[rest..].find(open).map(|start| rest + start) {
+        let content = start + open.len();
+        let Some(end) = html[content..].find(close).map(|end| content + end) else {
+            break;
+        };
+        found.push((start, end + close.len(), &html[content..end]));
+        rest = end + close.len();
+    }
+    found
+}
+
+/// Drops tags and resolves the entities the puzzle pages use
+fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Examples of every part described in `html`, the saved page of a puzzle
+///
+/// Every `<article>` is a part. Its answer is the last `<code><em>` outside of
+/// the example blocks and belongs to its first block, later ones are mostly
+/// intermediate states. Parts without a block of their own (part 2 often
+/// reuses the example) get the first block of the previous part.
+pub fn extract(html: &str) -> Vec<Part> {
+    let mut articles: Vec<&str> = elements(html, "<article", "</article>")
+        .into_iter()
+        .map(|(_, _, article)| article)
+        .collect();
+    if articles.is_empty() {
+        articles.push(html);
+    }
+
+    let mut parts: Vec<Part> = Vec::new();
+    for (part, article) in (1..).zip(articles) {
+        let blocks = elements(article, "<pre><code>", "</code></pre>");
+        let mut examples: Vec<Example> = blocks
+            .iter()
+            .map(|(_, _, block)| Example {
+                input: text(block),
+                answer: None,
+            })
+            .collect();
+
+        let mut prose = article.to_string();
+        for (start, end, _) in blocks.iter().rev() {
+            prose.replace_range(start..end, "");
+        }
+        let answer = elements(&prose, "<code><em>", "</em></code>")
+            .into_iter()
+            .chain(elements(&prose, "<em><code>", "</code></em>"))
+            .max_by_key(|(start, _, _)| *start)
+            .map(|(_, _, answer)| text(answer));
+
+        if examples.is_empty() {
+            let previous = parts.last().and_then(|part| part.examples.first());
+            examples.extend(previous.map(|example| Example {
+                input: example.input.clone(),
+                answer: None,
+            }));
+        }
+        if let Some(first) = examples.first_mut() {
+            first.answer = answer;
+        }
+        parts.push(Part { part, examples });
+    }
+    parts
+}
+
+/// Stores `parts` below `dir` as `partN/K.txt` and `partN/K.answer`, returns the written files
+pub fn write(dir: &Path, parts: &[Part]) -> anyhow::Result<Vec<PathBuf>> {
+    let mut written = Vec::new();
+    for part in parts {
+        let dir = dir.join(format!("part{}", part.part));
+        std::fs::create_dir_all(&dir).with_context(|| format!("creating {}", dir.display()))?;
+        for (index, example) in (1..).zip(&part.examples) {
+            let input = dir.join(format!("{}.txt", index));
+            let answer = example
+                .answer
+                .as_ref()
+                .map(|answer| (input.with_extension("answer"), answer));
+            for (path, contents) in std::iter::once((input.clone(), &example.input)).chain(answer) {
+                std::fs::write(&path, contents)
+                    .with_context(|| format!("writing {}", path.display()))?;
+                written.push(path);
+            }
+        }
+    }
+    Ok(written)
+}
+
+/// Reads an example written by [`write`]
+pub fn load(path: &Path) -> anyhow::Result<Example> {
+    let input =
+        std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let answer = match std::fs::read_to_string(path.with_extension("answer")) {
+        Ok(answer) => Some(answer.trim().to_string()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => {
+            return Err(e).with_context(|| format!("reading the answer of {}", path.display()))
+        }
+    };
+    Ok(Example { input, answer })
+}
+
+/// Checks `S` against the example at `path`
+///
+/// Meant for tests: blocks without an answer (e.g. intermediate states) are skipped.
+pub fn check<S: Solution>(path: &Path) -> anyhow::Result<()> {
+    let example = load(path)?;
+    let Some(expected) = example.answer else {
+        eprintln!("skipping: no answer for {}", path.display());
+        return Ok(());
+    };
+    let answer = S::process(&example.input)
+        .with_context(|| format!("example {}", path.display()))?
+        .to_string();
+    ensure!(
+        answer == expected,
+        "day {} part {} example {}: expected {}, got {}",
+        S::DAY,
+        S::PART,
+        path.display(),
+        expected,
+        answer
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 42: Test ---</h2><p>For example:</p>
+<pre><code>1 &lt; 2
+<em>3</em> &amp; 4
+</code></pre>
+<p>After a while:</p>
+<pre><code>5
+</code></pre>
+<p>Here the <code><em>3</em></code> is just emphasis, the total is <code><em>15</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Using the same example <em><code>a-b</code></em> gives <code><em>-7</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_extract() {
+        let parts = extract(PAGE);
+        assert_eq!(
+            vec![
+                Part {
+                    part: 1,
+                    examples: vec![
+                        Example {
+                            input: "1 < 2\n3 & 4\n".to_string(),
+                            answer: Some("15".to_string()),
+                        },
+                        Example {
+                            input: "5\n".to_string(),
+                            answer: None,
+                        },
+                    ],
+                },
+                Part {
+                    part: 2,
+                    examples: vec![Example {
+                        input: "1 < 2\n3 & 4\n".to_string(),
+                        answer: Some("-7".to_string()),
+                    }],
+                },
+            ],
+            parts
+        );
+    }
+
+    #[test]
+    fn test_write_and_load() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        let written = write(&dir, &extract(PAGE));
+        let first = load(&dir.join("part1/1.txt"));
+        let second = load(&dir.join("part1/2.txt"));
+        std::fs::remove_dir_all(&dir)?;
+
+        assert_eq!(5, written?.len());
+        assert_eq!(
+            Example {
+                input: "1 < 2\n3 & 4\n".to_string(),
+                answer: Some("15".to_string()),
+            },
+            first?
+        );
+        assert_eq!(None, second?.answer);
+        Ok(())
+    }
+}
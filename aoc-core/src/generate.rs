@@ -0,0 +1,88 @@
+//! Synthetic puzzle inputs
+//!
+//! Every day has a `generate` module emitting structurally valid inputs of a
+//! chosen size, so solutions can be stress tested and benchmarked without the
+//! (private) real inputs.
+use rand::SeedableRng;
+
+/// Random number generator the generators are driven by
+///
+/// ChaCha8 is portable, so a seed gives the same input on every machine.
+pub type GenRng = rand_chacha::ChaCha8Rng;
+
+/// Seeded [`GenRng`]
+pub fn rng(seed: u64) -> GenRng {
+    GenRng::seed_from_u64(seed)
+}
+
+/// Input generator of one day
+#[derive(Clone, Copy)]
+pub struct Generator {
+    pub day: u8,
+    /// Size used if none is given, roughly the one of a real input
+    pub default_size: usize,
+    generate: fn(&mut GenRng, usize) -> String,
+}
+
+impl Generator {
+    pub fn new(day: u8, default_size: usize, generate: fn(&mut GenRng, usize) -> String) -> Self {
+        Self {
+            day,
+            default_size,
+            generate,
+        }
+    }
+
+    /// Generates an input, the same `seed` and `size` always give the same input
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut rng(seed), size)
+    }
+}
+
+/// Overwrites bytes of `input`, `(position, byte)` with the position wrapping around
+///
+/// Turns a valid input into a slightly broken one, so fuzzing gets past the first tag of a parser.
+pub fn mutate(input: &str, edits: &[(u16, u8)]) -> String {
+    let mut bytes = input.as_bytes().to_vec();
+    if !bytes.is_empty() {
+        for &(position, byte) in edits {
+            let len = bytes.len();
+            bytes[position as usize % len] = byte;
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+impl std::fmt::Debug for Generator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day {:02} generator", self.day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn numbers(rng: &mut GenRng, size: usize) -> String {
+        (0..size)
+            .map(|_| rng.gen_range(0..100).to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_seeded() {
+        let generator = Generator::new(1, 10, numbers);
+        assert_eq!(generator.generate(7, 10), generator.generate(7, 10));
+        assert_ne!(generator.generate(7, 10), generator.generate(8, 10));
+        assert_eq!(generator.generate(7, 3).lines().count(), 3);
+    }
+
+    #[test]
+    fn test_mutate() {
+        assert_eq!(mutate("abc", &[(1, b'x'), (5, b'y')]), "axy");
+        assert_eq!(mutate("", &[(1, b'x')]), "");
+        assert_eq!(mutate("abc", &[(0, 0xff)]), "\u{fffd}bc");
+    }
+}
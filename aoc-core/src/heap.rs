@@ -0,0 +1,119 @@
+//! Heap usage of the solutions
+//!
+//! [`Counting`] wraps the system allocator and counts what goes through it.
+//! The runner installs it as global allocator with the `heap-profile` feature,
+//! [`measure`] then reports what a closure allocated. Without it all stats stay
+//! zero.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering::Relaxed},
+};
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static TOTAL: AtomicUsize = AtomicUsize::new(0);
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting allocations
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOC: aoc_core::heap::Counting = aoc_core::heap::Counting;
+/// ```
+pub struct Counting;
+
+impl Counting {
+    fn allocated(size: usize) {
+        let current = CURRENT.fetch_add(size, Relaxed) + size;
+        PEAK.fetch_max(current, Relaxed);
+        TOTAL.fetch_add(size, Relaxed);
+        COUNT.fetch_add(1, Relaxed);
+    }
+}
+
+// SAFETY: every call is forwarded to `System` unchanged
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Relaxed);
+    }
+
+    /// Counted like freeing the old block and allocating a new one, as dhat does
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            CURRENT.fetch_sub(layout.size(), Relaxed);
+            Self::allocated(new_size);
+        }
+        new
+    }
+}
+
+/// What happened on the heap while running a closure
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HeapStats {
+    /// Most bytes live at once, on top of what was live before
+    pub peak: usize,
+    /// Bytes allocated in total
+    pub total: usize,
+    /// Number of allocations
+    pub allocations: usize,
+}
+
+/// Runs `f` and counts its allocations
+///
+/// Allocations of other threads are counted as well, so measure one thing at a time.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, HeapStats) {
+    let base = CURRENT.load(Relaxed);
+    PEAK.store(base, Relaxed);
+    let (total, count) = (TOTAL.load(Relaxed), COUNT.load(Relaxed));
+    let result = f();
+    let stats = HeapStats {
+        peak: PEAK.load(Relaxed).saturating_sub(base),
+        total: TOTAL.load(Relaxed) - total,
+        allocations: COUNT.load(Relaxed) - count,
+    };
+    (result, stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counting() {
+        // tests run without the counting allocator, so drive it by hand
+        let (_, stats) = measure(|| unsafe {
+            let layout = Layout::from_size_align(100, 8).expect("valid layout");
+            let ptr = Counting.alloc(layout);
+            let ptr = Counting.realloc(ptr, layout, 300);
+            Counting.dealloc(ptr, Layout::from_size_align(300, 8).expect("valid layout"));
+            let ptr = Counting.alloc(layout);
+            Counting.dealloc(ptr, layout);
+        });
+        assert_eq!(
+            HeapStats {
+                peak: 300,
+                total: 500,
+                allocations: 3,
+            },
+            stats
+        );
+    }
+}
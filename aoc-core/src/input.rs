@@ -0,0 +1,158 @@
+use anyhow::{bail, Context};
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable overriding the input directory
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+/// Name of the input used when none is given
+pub const DEFAULT_ID: &str = "input";
+
+/// Directory holding the (private) puzzle inputs
+///
+/// Inputs are stored as `<dir>/day-XX/<id>.txt` so several inputs
+/// per day can live next to each other.
+#[derive(Debug, Clone)]
+pub struct Inputs {
+    dir: PathBuf,
+}
+
+impl Inputs {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Uses `AOC_INPUT_DIR` or falls back to `inputs/` in the workspace root
+    pub fn from_env() -> Self {
+        match std::env::var_os(INPUT_DIR_ENV) {
+            Some(dir) => Self::new(dir),
+            None => Self::new(workspace_root().join("inputs")),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, day: u8, id: &str) -> PathBuf {
+        self.dir
+            .join(format!("day-{:02}", day))
+            .join(format!("{id}.txt"))
+    }
+
+    /// Ids of all inputs stored for `day`, sorted
+    pub fn ids(&self, day: u8) -> anyhow::Result<Vec<String>> {
+        let dir = self.dir.join(format!("day-{:02}", day));
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e).with_context(|| format!("reading {}", dir.display())),
+        };
+        let mut ids = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "txt") {
+                if let Some(id) = path.file_stem().and_then(|stem| stem.to_str()) {
+                    ids.push(id.to_string());
+                }
+            }
+        }
+        ids.sort();
+        Ok(ids)
+    }
+
+    /// Reads an input, failing with a readable message if it does not exist
+    pub fn load(&self, day: u8, id: &str) -> anyhow::Result<String> {
+        match self.try_load(day, id)? {
+            Some(input) => Ok(input),
+            None => bail!(
+                "no input `{}` for day {} at {}. Pass a path, `-` for stdin, set {} or run `aoc fetch {}`",
+                id,
+                day,
+                self.path(day, id).display(),
+                INPUT_DIR_ENV,
+                day
+            ),
+        }
+    }
+
+    /// Reads an input, `None` if it does not exist
+    pub fn try_load(&self, day: u8, id: &str) -> anyhow::Result<Option<String>> {
+        let path = self.path(day, id);
+        match std::fs::read_to_string(&path) {
+            Ok(input) => Ok(Some(input)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e).with_context(|| format!("reading {}", path.display())),
+        }
+    }
+}
+
+/// Root of the cargo workspace this crate was built in
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc-core lives inside the workspace")
+        .to_path_buf()
+}
+
+/// Reads the input from a path, stdin (`-`) or the input directory
+pub fn load(day: u8, arg: Option<&str>) -> anyhow::Result<String> {
+    match arg {
+        Some("-") => {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .context("reading stdin")?;
+            Ok(input)
+        }
+        Some(path) => std::fs::read_to_string(path).with_context(|| format!("reading {}", path)),
+        None => Inputs::from_env().load(day, DEFAULT_ID),
+    }
+}
+
+/// Reads the input given as first command line argument
+///
+/// See [`load`] for where it is looked up.
+pub fn from_args(day: u8) -> anyhow::Result<String> {
+    load(day, std::env::args().nth(1).as_deref())
+}
+
+/// Default input for tests which check against a personal input
+///
+/// Returns `None` (and says so) if it doesn't exist, so those tests
+/// can skip instead of failing on machines without the input.
+pub fn optional(day: u8) -> anyhow::Result<Option<String>> {
+    let inputs = Inputs::from_env();
+    let input = inputs.try_load(day, DEFAULT_ID)?;
+    if input.is_none() {
+        eprintln!(
+            "skipping: no input at {}",
+            inputs.path(day, DEFAULT_ID).display()
+        );
+    }
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        let inputs = Inputs::new("inputs");
+        assert_eq!(
+            inputs.path(3, DEFAULT_ID),
+            Path::new("inputs").join("day-03").join("input.txt")
+        );
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let inputs = Inputs::new(std::env::temp_dir().join("aoc-core-missing-inputs"));
+        assert!(inputs.try_load(1, DEFAULT_ID).unwrap().is_none());
+        assert!(inputs.ids(1).unwrap().is_empty());
+        let error = inputs.load(1, DEFAULT_ID).unwrap_err().to_string();
+        assert!(error.contains("no input `input` for day 1"), "{error}");
+    }
+}
@@ -0,0 +1,12 @@
+pub mod answers;
+pub mod examples;
+pub mod generate;
+pub mod heap;
+pub mod input;
+pub mod parse;
+pub mod snapshot;
+pub mod solution;
+
+pub use answers::Answers;
+pub use parse::ParseError;
+pub use solution::{Panicked, Phase, Profile, Run, Solution, Solver};
@@ -0,0 +1,295 @@
+//! Parse errors pointing into the puzzle input
+use nom::{
+    error::{ErrorKind, VerboseError, VerboseErrorKind},
+    Parser,
+};
+use std::fmt;
+
+/// Characters shown on each side of the failure for long lines
+const WINDOW: usize = 40;
+
+/// Where in the input parsing failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// 1-based
+    pub line: usize,
+    /// 1-based, counted in chars
+    pub column: usize,
+    /// The offending line, shortened around `column` if it is long
+    pub snippet: String,
+    /// Char index of the failure inside `snippet`
+    caret: usize,
+}
+
+/// Malformed puzzle input
+///
+/// ```
+/// use aoc_core::ParseError;
+/// let error = ParseError::at_position("1 2\n3 x\n", 1, 2, "expected a number");
+/// assert_eq!(
+///     error.to_string(),
+///     "expected a number at line 2, column 3\n  |\n2 | 3 x\n  |   ^"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub location: Option<Location>,
+}
+
+impl ParseError {
+    /// Error without a known location
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            location: None,
+        }
+    }
+
+    /// Error at byte `offset` of `input`
+    pub fn at_offset(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        let mut offset = offset.min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |idx| offset + idx);
+        let line: Vec<char> = input[line_start..line_end]
+            .trim_end_matches('\r')
+            .chars()
+            .collect();
+        let column = input[line_start..offset].chars().count();
+
+        let start = column.saturating_sub(WINDOW);
+        let end = line.len().min(column + WINDOW);
+        let mut snippet = String::new();
+        if start > 0 {
+            snippet.push('…');
+        }
+        snippet.extend(&line[start..end.max(start)]);
+        if end < line.len() {
+            snippet.push('…');
+        }
+
+        Self {
+            message: message.into(),
+            location: Some(Location {
+                line: before.matches('\n').count() + 1,
+                column: column + 1,
+                snippet,
+                caret: column - start + usize::from(start > 0),
+            }),
+        }
+    }
+
+    /// Error at the start of `rest`, which has to be a sub slice of `input`
+    ///
+    /// This is what nom hands back as the unparsed input.
+    pub fn at(input: &str, rest: &str, message: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let position = rest.as_ptr() as usize;
+        let offset = if (start..=start + input.len()).contains(&position) {
+            position - start
+        } else {
+            input.len().saturating_sub(rest.len())
+        };
+        Self::at_offset(input, offset, message)
+    }
+
+    /// Error at 0-based `line` and `column` (in chars)
+    pub fn at_position(
+        input: &str,
+        line: usize,
+        column: usize,
+        message: impl Into<String>,
+    ) -> Self {
+        let line_start: usize = input.split_inclusive('\n').take(line).map(str::len).sum();
+        let offset = input[line_start..]
+            .char_indices()
+            .take_while(|(_, c)| *c != '\n' && *c != '\r')
+            .nth(column)
+            .map_or_else(
+                || {
+                    let rest = &input[line_start..];
+                    line_start + rest.find(['\r', '\n']).unwrap_or(rest.len())
+                },
+                |(idx, _)| line_start + idx,
+            );
+        Self::at_offset(input, offset, message)
+    }
+
+    /// Error at `column` (in chars) of a single line of the input
+    ///
+    /// For parsers which work line by line and don't see the whole input.
+    /// `line_idx` is 0-based.
+    pub fn in_line(line: &str, line_idx: usize, column: usize, message: impl Into<String>) -> Self {
+        let mut error = Self::at_position(line, 0, column, message);
+        if let Some(location) = error.location.as_mut() {
+            location.line = line_idx + 1;
+        }
+        error
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        if let Some(location) = &self.location {
+            let gutter = " ".repeat(location.line.to_string().len());
+            write!(
+                f,
+                " at line {}, column {}\n{gutter} |\n{} | {}\n{gutter} | {}^",
+                location.line,
+                location.column,
+                location.line,
+                location.snippet,
+                " ".repeat(location.caret)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// nom errors which know where in the input they happened
+pub trait NomError<'a> {
+    fn into_parse_error(self, input: &'a str) -> ParseError;
+}
+
+impl<'a> NomError<'a> for nom::error::Error<&'a str> {
+    fn into_parse_error(self, input: &'a str) -> ParseError {
+        ParseError::at(input, self.input, describe(self.code))
+    }
+}
+
+/// Uses the innermost error for the location and adds the contexts around it
+impl<'a> NomError<'a> for VerboseError<&'a str> {
+    fn into_parse_error(self, input: &'a str) -> ParseError {
+        let Some((rest, kind)) = self.errors.first() else {
+            return ParseError::new("invalid input");
+        };
+        let mut message = match kind {
+            VerboseErrorKind::Char(c) => format!("expected {:?}", c),
+            VerboseErrorKind::Context(context) => format!("expected {}", context),
+            VerboseErrorKind::Nom(kind) => describe(*kind),
+        };
+        for (_, kind) in self.errors.iter().skip(1) {
+            if let VerboseErrorKind::Context(context) = kind {
+                message.push_str(" in ");
+                message.push_str(context);
+            }
+        }
+        ParseError::at(input, rest, message)
+    }
+}
+
+fn describe(kind: ErrorKind) -> String {
+    format!("unexpected input ({})", kind.description().to_lowercase())
+}
+
+/// Runs a nom parser on the whole input
+///
+/// Only whitespace may be left over, anything else is reported as an error.
+///
+/// ```
+/// use aoc_core::parse::parse_all;
+/// use nom::{character::complete::{line_ending, u32}, multi::separated_list1};
+///
+/// let numbers = parse_all("1\n2\n", separated_list1(line_ending, u32::<_, nom::error::Error<_>>));
+/// assert_eq!(vec![1, 2], numbers.unwrap());
+/// let error = parse_all("1\nx\n", separated_list1(line_ending, u32::<_, nom::error::Error<_>>));
+/// assert_eq!(2, error.unwrap_err().location.unwrap().line);
+/// ```
+pub fn parse_all<'a, O, E>(
+    input: &'a str,
+    mut parser: impl Parser<&'a str, O, E>,
+) -> Result<O, ParseError>
+where
+    E: NomError<'a>,
+{
+    match parser.parse(input) {
+        Ok((rest, output)) if rest.trim().is_empty() => Ok(output),
+        Ok((rest, _)) => Err(ParseError::at(input, rest.trim_start(), "unexpected input")),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(e.into_parse_error(input)),
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::at_offset(
+            input,
+            input.len(),
+            "unexpected end of input",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{self, line_ending},
+        error::context,
+        multi::separated_list1,
+        sequence::preceded,
+        IResult,
+    };
+
+    #[test]
+    fn test_location() {
+        let input = "abc\r\ndéf\r\nghi";
+        let error = ParseError::at_position(input, 1, 2, "nope");
+        let location = error.location.clone().unwrap();
+        assert_eq!((2, 3), (location.line, location.column));
+        assert_eq!("déf", location.snippet);
+        assert_eq!(error, ParseError::at(input, &input[8..], "nope"));
+
+        let end = ParseError::at_offset(input, input.len(), "end")
+            .location
+            .unwrap();
+        assert_eq!((3, 4), (end.line, end.column));
+    }
+
+    #[test]
+    fn test_in_line() {
+        let error = ParseError::in_line("1 2 x", 9, 4, "nope");
+        assert_eq!(
+            "nope at line 10, column 5\n   |\n10 | 1 2 x\n   |     ^",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_long_lines_are_shortened() {
+        let input = "x".repeat(200);
+        let error = ParseError::at_offset(&input, 100, "here");
+        let location = error.location.as_ref().unwrap();
+        assert_eq!(101, location.column);
+        assert_eq!(2 * WINDOW + 2, location.snippet.chars().count());
+        assert!(error
+            .to_string()
+            .ends_with(&format!("{}^", " ".repeat(WINDOW + 1))));
+    }
+
+    fn numbers(input: &str) -> IResult<&str, Vec<u32>, VerboseError<&str>> {
+        separated_list1(
+            line_ending,
+            context("numbers", preceded(tag("n: "), complete::u32)),
+        )(input)
+    }
+
+    #[test]
+    fn test_verbose_error() {
+        let error = parse_all("n: x", numbers).unwrap_err();
+        assert_eq!("unexpected input (digit) in numbers", error.message);
+        assert_eq!(4, error.location.unwrap().column);
+    }
+
+    #[test]
+    fn test_left_over_input() {
+        assert_eq!(vec![1, 2], parse_all("n: 1\nn: 2\n\n", numbers).unwrap());
+        let error = parse_all("n: 1\nn 2\n", numbers).unwrap_err();
+        assert_eq!("unexpected input", error.message);
+        assert_eq!(2, error.location.unwrap().line);
+    }
+}
@@ -0,0 +1,72 @@
+//! Snapshot tests: output compared against a file committed next to the test
+//!
+//! A missing snapshot fails the test. New snapshots and intended changes are
+//! written by running the tests with `UPDATE_SNAPSHOTS=1`, review the diff.
+use anyhow::{bail, ensure, Context};
+use std::path::Path;
+
+/// Set to write the snapshots instead of comparing against them
+pub const UPDATE_ENV: &str = "UPDATE_SNAPSHOTS";
+
+/// Checks `actual` against the snapshot at `path`, relative to the crate of the test
+pub fn check(path: impl AsRef<Path>, actual: impl AsRef<[u8]>) -> anyhow::Result<()> {
+    compare(
+        path.as_ref(),
+        actual.as_ref(),
+        std::env::var_os(UPDATE_ENV).is_some(),
+    )
+}
+
+fn compare(path: &Path, actual: &[u8], update: bool) -> anyhow::Result<()> {
+    if update {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+        }
+        return std::fs::write(path, actual).with_context(|| format!("writing {}", path.display()));
+    }
+    if !path.exists() {
+        bail!(
+            "no snapshot at {}, run with {}=1 to write it",
+            path.display(),
+            UPDATE_ENV
+        );
+    }
+    let expected = std::fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+    ensure!(
+        expected == actual,
+        "output differs from snapshot {}, rerun with {}=1 if the change is intended",
+        path.display(),
+        UPDATE_ENV
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-snapshot-{}", std::process::id()));
+        let path = dir.join("nested/picture.svg");
+        let missing = compare(&path, b"<svg/>", false);
+        let written = compare(&path, b"<svg/>", true);
+        let same = compare(&path, b"<svg/>", false);
+        let changed = compare(&path, b"<svg></svg>", false);
+        let stored = std::fs::read_to_string(&path);
+        std::fs::remove_dir_all(&dir)?;
+
+        assert!(missing
+            .expect_err("not written yet")
+            .to_string()
+            .contains("no snapshot"));
+        written?;
+        same?;
+        assert!(changed
+            .expect_err("differs")
+            .to_string()
+            .contains(UPDATE_ENV));
+        assert_eq!("<svg/>", stored?);
+        Ok(())
+    }
+}
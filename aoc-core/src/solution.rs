@@ -0,0 +1,340 @@
+use crate::heap::{self, HeapStats};
+use anyhow::Context;
+use std::{
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+/// One part of one day of the puzzle
+///
+/// Parsing and solving are split so the runner can time them separately.
+/// `process` glues both together and is what the tests usually call. Both
+/// phases run inside a `parse` and a `solve` tracing span, solutions only
+/// emit events and never install a subscriber.
+///
+/// ```
+/// use aoc_core::Solution;
+///
+/// struct Sum;
+///
+/// impl Solution for Sum {
+///     const DAY: u8 = 1;
+///     const PART: u8 = 1;
+///     type Input<'a> = Vec<u32>;
+///     type Answer = u32;
+///
+///     fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+///         Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+///     }
+///
+///     fn solve(input: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+///         Ok(input.iter().sum())
+///     }
+/// }
+///
+/// assert_eq!(6, Sum::process("1\n2\n3").unwrap());
+/// ```
+pub trait Solution {
+    const DAY: u8;
+    const PART: u8;
+
+    /// Parsed puzzle input. May borrow from the raw input
+    type Input<'a>;
+    /// The answer which gets submitted
+    type Answer: Display;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>>;
+    fn solve(input: Self::Input<'_>) -> anyhow::Result<Self::Answer>;
+
+    fn process(input: &str) -> anyhow::Result<Self::Answer> {
+        let parsed = parse_span::<Self>().in_scope(|| Self::parse(input))?;
+        solve_span::<Self>().in_scope(|| Self::solve(parsed))
+    }
+}
+
+/// Result of a single timed run of a [`Solution`]
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Run {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+/// Phase of a [`Solution`] an error of [`Solver::run`] happened in
+///
+/// Attached as context, so it can be found with `error.downcast_ref::<Phase>()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Solve,
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parsing input"),
+            Phase::Solve => write!(f, "solving"),
+        }
+    }
+}
+
+/// A [`Solution`] panicked instead of returning an error, e.g. on a `todo!()`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panicked(pub String);
+
+impl Display for Panicked {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "panicked: {}", self.0)
+    }
+}
+
+impl std::error::Error for Panicked {}
+
+/// Heap usage of a single run of a [`Solution`], see [`heap`]
+#[derive(Debug, Clone)]
+pub struct Profile {
+    pub answer: String,
+    pub parse: HeapStats,
+    pub solve: HeapStats,
+}
+
+/// Type erased [`Solution`] so all days can live in one list
+#[derive(Clone, Copy)]
+pub struct Solver {
+    pub day: u8,
+    pub part: u8,
+    run: fn(&str) -> anyhow::Result<Run>,
+    profile: fn(&str) -> anyhow::Result<Profile>,
+}
+
+impl Solver {
+    pub fn new<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            part: S::PART,
+            run: run::<S>,
+            profile: profile::<S>,
+        }
+    }
+
+    pub fn run(&self, input: &str) -> anyhow::Result<Run> {
+        (self.run)(input)
+    }
+
+    /// Like [`Solver::run`], but a panic is returned as [`Panicked`] error
+    ///
+    /// The panic hook still runs, so the message and location are printed as usual.
+    pub fn run_isolated(&self, input: &str) -> anyhow::Result<Run> {
+        isolated(|| self.run(input))
+    }
+
+    /// Counts the allocations of both phases, needs [`heap::Counting`] as global allocator
+    pub fn profile(&self, input: &str) -> anyhow::Result<Profile> {
+        (self.profile)(input)
+    }
+
+    /// Like [`Solver::profile`], but a panic is returned as [`Panicked`] error
+    ///
+    /// The panic hook still runs, so the message and location are printed as usual.
+    pub fn profile_isolated(&self, input: &str) -> anyhow::Result<Profile> {
+        isolated(|| self.profile(input))
+    }
+}
+
+impl std::fmt::Debug for Solver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day {:02} part {}", self.day, self.part)
+    }
+}
+
+/// Runs `f`, a panic becomes a [`Panicked`] error
+fn isolated<T>(f: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("unknown cause");
+        Err(Panicked(message.to_string()).into())
+    })
+}
+
+fn parse_span<S: Solution + ?Sized>() -> tracing::Span {
+    tracing::info_span!("parse", day = S::DAY, part = S::PART)
+}
+
+fn solve_span<S: Solution + ?Sized>() -> tracing::Span {
+    tracing::info_span!("solve", day = S::DAY, part = S::PART)
+}
+
+fn run<S: Solution>(input: &str) -> anyhow::Result<Run> {
+    let start = Instant::now();
+    let parsed = parse_span::<S>()
+        .in_scope(|| S::parse(input))
+        .context(Phase::Parse)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let answer = solve_span::<S>()
+        .in_scope(|| S::solve(parsed))
+        .context(Phase::Solve)?;
+    let solve = start.elapsed();
+
+    Ok(Run {
+        answer: answer.to_string(),
+        parse,
+        solve,
+    })
+}
+
+fn profile<S: Solution>(input: &str) -> anyhow::Result<Profile> {
+    let (parsed, parse) = heap::measure(|| S::parse(input));
+    let parsed = parsed.context(Phase::Parse)?;
+    // the input is dropped in here, which belongs to solving
+    let (answer, solve) = heap::measure(|| S::solve(parsed).map(|answer| answer.to_string()));
+
+    Ok(Profile {
+        answer: answer.context(Phase::Solve)?,
+        parse,
+        solve,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::Write,
+        sync::{Arc, Mutex},
+    };
+    use tracing_subscriber::fmt::format::FmtSpan;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        const DAY: u8 = 1;
+        const PART: u8 = 2;
+        type Input<'a> = usize;
+        type Answer = usize;
+
+        fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+            Ok(input.lines().count())
+        }
+
+        fn solve(input: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+            tracing::info!(input, "solving");
+            Ok(input)
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().expect("not poisoned").write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_spans() -> anyhow::Result<()> {
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_span_events(FmtSpan::CLOSE)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        tracing::subscriber::with_default(subscriber, || {
+            // solutions never install a subscriber, so running twice is fine
+            Lines::process("a\nb")?;
+            Solver::new::<Lines>().run("a\nb")
+        })?;
+
+        let output = String::from_utf8(buffer.0.lock().expect("not poisoned").clone())?;
+        // both runs: parse closes, solve logs and closes
+        for run in output.lines().collect::<Vec<_>>().chunks(3) {
+            assert_eq!(3, run.len());
+            assert!(run[0].contains("parse{day=1 part=2}: aoc_core::solution: close"));
+            assert!(
+                run[1].contains("solve{day=1 part=2}: aoc_core::solution::tests: solving input=2")
+            );
+            assert!(run[2].contains("solve{day=1 part=2}: aoc_core::solution: close"));
+        }
+        assert_eq!(6, output.lines().count());
+        Ok(())
+    }
+
+    #[test]
+    fn test_phase() {
+        struct Failing;
+
+        impl Solution for Failing {
+            const DAY: u8 = 1;
+            const PART: u8 = 1;
+            type Input<'a> = &'a str;
+            type Answer = usize;
+
+            fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+                anyhow::ensure!(!input.is_empty(), "empty input");
+                Ok(input)
+            }
+
+            fn solve(input: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+                Ok(input.parse()?)
+            }
+        }
+
+        let solver = Solver::new::<Failing>();
+        let parse = solver.run("").expect_err("nothing to parse");
+        let solve = solver.run("x").expect_err("not a number");
+        assert_eq!(Some(&Phase::Parse), parse.downcast_ref::<Phase>());
+        assert_eq!("parsing input: empty input", format!("{:#}", parse));
+        assert_eq!(Some(&Phase::Solve), solve.downcast_ref::<Phase>());
+    }
+
+    #[test]
+    fn test_isolated() {
+        struct Unfinished;
+
+        impl Solution for Unfinished {
+            const DAY: u8 = 17;
+            const PART: u8 = 1;
+            type Input<'a> = &'a str;
+            type Answer = usize;
+
+            fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+                Ok(input)
+            }
+
+            fn solve(_input: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+                panic!("unfinished")
+            }
+        }
+
+        let unfinished = Solver::new::<Unfinished>();
+        let run = unfinished.run_isolated("").expect_err("panics");
+        assert_eq!(
+            Some(&Panicked("unfinished".to_string())),
+            run.downcast_ref::<Panicked>()
+        );
+        assert_eq!("panicked: unfinished", run.to_string());
+        let profile = unfinished.profile_isolated("").expect_err("panics");
+        assert_eq!("panicked: unfinished", profile.to_string());
+
+        let lines = Solver::new::<Lines>();
+        assert_eq!("1", lines.run_isolated("x").unwrap().answer);
+        assert_eq!("1", lines.profile_isolated("x").unwrap().answer);
+    }
+}
@@ -0,0 +1,154 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Position of a cell inside a grid
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Coordinate {
+    pub column: usize,
+    pub line: usize,
+}
+
+impl Coordinate {
+    pub const fn new(column: usize, line: usize) -> Self {
+        Self { column, line }
+    }
+
+    /// Moves by `offset`, `None` if the result would be negative
+    ///
+    /// ```
+    /// use aoc_grid::{Coordinate, Point};
+    /// let origin = Coordinate::new(0, 0);
+    /// assert_eq!(Some(Coordinate::new(1, 0)), origin.checked_add(Point::new(1, 0)));
+    /// assert_eq!(None, origin.checked_add(Point::new(0, -1)));
+    /// ```
+    pub fn checked_add(self, offset: Point) -> Option<Self> {
+        (Point::from(self) + offset).try_into().ok()
+    }
+
+    /// Taxicab distance between two coordinates
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.column.abs_diff(other.column) + self.line.abs_diff(other.line)
+    }
+}
+
+/// Signed position or offset, which may lie outside of any grid
+///
+/// `x` grows to the right (columns), `y` grows downwards (lines).
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ZERO: Self = Self::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    /// Clamps both components to `-1..=1`
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn manhattan_length(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+}
+
+impl From<Coordinate> for Point {
+    fn from(value: Coordinate) -> Self {
+        Self::new(value.column as isize, value.line as isize)
+    }
+}
+
+impl TryFrom<Point> for Coordinate {
+    type Error = &'static str;
+
+    fn try_from(value: Point) -> Result<Self, Self::Error> {
+        match (usize::try_from(value.x), usize::try_from(value.y)) {
+            (Ok(column), Ok(line)) => Ok(Coordinate { column, line }),
+            _ => Err("negative point"),
+        }
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Self;
+    fn mul(self, rhs: isize) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+/// Anything that can address a cell of a `width` x `height` grid
+pub trait GridIndex: Copy {
+    /// `None` if the index lies outside of the grid
+    fn coordinate(self, width: usize, height: usize) -> Option<Coordinate>;
+}
+
+impl GridIndex for Coordinate {
+    fn coordinate(self, width: usize, height: usize) -> Option<Coordinate> {
+        (self.column < width && self.line < height).then_some(self)
+    }
+}
+
+impl GridIndex for Point {
+    fn coordinate(self, width: usize, height: usize) -> Option<Coordinate> {
+        Coordinate::try_from(self)
+            .ok()
+            .and_then(|c| c.coordinate(width, height))
+    }
+}
+
+/// `(column, line)`
+impl GridIndex for (usize, usize) {
+    fn coordinate(self, width: usize, height: usize) -> Option<Coordinate> {
+        Coordinate::new(self.0, self.1).coordinate(width, height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Point::new(0, 0), Some(Coordinate::new(0, 0)))]
+    #[case(Point::new(2, 1), Some(Coordinate::new(2, 1)))]
+    #[case(Point::new(3, 1), None)]
+    #[case(Point::new(1, 2), None)]
+    #[case(Point::new(-1, 0), None)]
+    fn test_point_index(#[case] point: Point, #[case] expected: Option<Coordinate>) {
+        assert_eq!(point.coordinate(3, 2), expected);
+    }
+
+    #[test]
+    fn test_manhattan_distance() {
+        let a = Coordinate::new(1, 6);
+        let b = Coordinate::new(5, 11);
+        assert_eq!(a.manhattan_distance(b), 9);
+        assert_eq!(b.manhattan_distance(a), 9);
+        assert_eq!((Point::from(a) - Point::from(b)).manhattan_length(), 9);
+    }
+}
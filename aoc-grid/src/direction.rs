@@ -0,0 +1,288 @@
+use std::{fmt, ops::Add};
+
+use crate::coordinate::{Coordinate, Point};
+
+/// One of the 4 orthogonal directions
+///
+/// `y` grows downwards, so `North` is `(0, -1)`.
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    /// All directions, in declaration order
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::South,
+        Direction::East,
+        Direction::West,
+    ];
+
+    /// ```
+    /// use aoc_grid::Direction::*;
+    /// assert_eq!(West, North.turn_left());
+    /// assert_eq!(North, North.turn_left().turn_left().turn_left().turn_left());
+    /// ```
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::West => Self::South,
+            Self::South => Self::East,
+            Self::East => Self::North,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::East => Self::West,
+            Self::West => Self::East,
+            Self::North => Self::South,
+            Self::South => Self::North,
+        }
+    }
+
+    /// `true` for North and South
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::North | Self::South)
+    }
+
+    /// Unit vector pointing into this direction
+    pub fn offset(self) -> Point {
+        match self {
+            Self::North => Point::new(0, -1),
+            Self::South => Point::new(0, 1),
+            Self::East => Point::new(1, 0),
+            Self::West => Point::new(-1, 0),
+        }
+    }
+
+    /// Direction of the straight line going from `from` to `to`
+    ///
+    /// ```
+    /// use aoc_grid::{Direction, Point};
+    /// assert_eq!(Ok(Direction::East), Direction::between(Point::new(1, 1), Point::new(4, 1)));
+    /// assert!(Direction::between(Point::new(1, 1), Point::new(2, 2)).is_err());
+    /// ```
+    pub fn between(from: Point, to: Point) -> Result<Self, InvalidDelta> {
+        (to - from).try_into()
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(value: Direction) -> Self {
+        value.offset()
+    }
+}
+
+/// Only the sign of the delta is considered, `(0, 5)` is South
+impl TryFrom<Point> for Direction {
+    type Error = InvalidDelta;
+
+    fn try_from(value: Point) -> Result<Self, Self::Error> {
+        match (value.x.signum(), value.y.signum()) {
+            (0, -1) => Ok(Direction::North),
+            (0, 1) => Ok(Direction::South),
+            (1, 0) => Ok(Direction::East),
+            (-1, 0) => Ok(Direction::West),
+            _ => Err(InvalidDelta(value)),
+        }
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+    fn add(self, rhs: Direction) -> Self::Output {
+        self + rhs.offset()
+    }
+}
+
+impl Coordinate {
+    /// Neighbouring coordinate in `direction`
+    ///
+    /// `None` if it would leave the grid on the top or left side
+    pub fn step(self, direction: impl Into<Point>) -> Option<Coordinate> {
+        self.checked_add(direction.into())
+    }
+}
+
+/// One of the 8 compass directions, clockwise starting North
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// Turns by 45 degrees counter clockwise
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Turns by 45 degrees clockwise
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    pub fn offset(self) -> Point {
+        match self {
+            Self::North => Point::new(0, -1),
+            Self::NorthEast => Point::new(1, -1),
+            Self::East => Point::new(1, 0),
+            Self::SouthEast => Point::new(1, 1),
+            Self::South => Point::new(0, 1),
+            Self::SouthWest => Point::new(-1, 1),
+            Self::West => Point::new(-1, 0),
+            Self::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    pub fn between(from: Point, to: Point) -> Result<Self, InvalidDelta> {
+        (to - from).try_into()
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::North => Self::North,
+            Direction::South => Self::South,
+            Direction::East => Self::East,
+            Direction::West => Self::West,
+        }
+    }
+}
+
+impl TryFrom<Direction8> for Direction {
+    type Error = InvalidDelta;
+
+    fn try_from(value: Direction8) -> Result<Self, Self::Error> {
+        value.offset().try_into()
+    }
+}
+
+impl From<Direction8> for Point {
+    fn from(value: Direction8) -> Self {
+        value.offset()
+    }
+}
+
+/// Only straight lines and exact diagonals are accepted, `(2, -2)` is NorthEast
+impl TryFrom<Point> for Direction8 {
+    type Error = InvalidDelta;
+
+    fn try_from(value: Point) -> Result<Self, Self::Error> {
+        if value.x != 0 && value.y != 0 && value.x.abs() != value.y.abs() {
+            return Err(InvalidDelta(value));
+        }
+        let unit = value.signum();
+        Self::ALL
+            .into_iter()
+            .find(|direction| direction.offset() == unit)
+            .ok_or(InvalidDelta(value))
+    }
+}
+
+impl Add<Direction8> for Point {
+    type Output = Point;
+    fn add(self, rhs: Direction8) -> Self::Output {
+        self + rhs.offset()
+    }
+}
+
+/// Delta which doesn't point into any direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDelta(pub Point);
+
+impl fmt::Display for InvalidDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}) is no direction", self.0.x, self.0.y)
+    }
+}
+
+impl std::error::Error for InvalidDelta {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Direction::North)]
+    #[case(Direction::South)]
+    #[case(Direction::East)]
+    #[case(Direction::West)]
+    fn test_turns(#[case] direction: Direction) {
+        assert_eq!(direction.opposite(), direction.turn_left().turn_left());
+        assert_eq!(direction.opposite(), direction.turn_right().turn_right());
+        assert_eq!(direction, direction.turn_left().turn_right());
+        assert_eq!(direction.offset(), -direction.opposite().offset());
+        assert_eq!(Ok(direction), Direction::try_from(direction.offset() * 3));
+        assert_eq!(
+            Direction8::from(direction).turn_right().turn_right(),
+            direction.turn_right().into()
+        );
+    }
+
+    #[rstest]
+    #[case(Point::new(0, 0))]
+    #[case(Point::new(1, 1))]
+    #[case(Point::new(-2, 3))]
+    fn test_invalid_delta(#[case] delta: Point) {
+        assert_eq!(Err(InvalidDelta(delta)), Direction::try_from(delta));
+    }
+
+    #[test]
+    fn test_direction8() {
+        for direction in Direction8::ALL {
+            assert_eq!(direction, direction.turn_left().turn_right());
+            assert_eq!(direction.offset(), -direction.opposite().offset());
+            assert_eq!(Ok(direction), Direction8::try_from(direction.offset() * 2));
+            assert_eq!(
+                direction.is_diagonal(),
+                Direction::try_from(direction).is_err()
+            );
+        }
+        assert_eq!(
+            Err(InvalidDelta(Point::new(1, 2))),
+            Direction8::try_from(Point::new(1, 2))
+        );
+    }
+}
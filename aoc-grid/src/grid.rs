@@ -0,0 +1,486 @@
+use aoc_core::ParseError;
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::coordinate::{Coordinate, GridIndex, Point};
+use crate::direction::{Direction, Direction8};
+
+/// Dense, row-major two dimensional grid
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `width` x `height` cells, all set to `fill`
+    ///
+    /// Fails if either is 0, like [`Grid::from_vec`] a grid has at least one cell.
+    pub fn new(width: usize, height: usize, fill: T) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        if width == 0 || height == 0 {
+            return Err(ParseError::new("empty grid"));
+        }
+        Ok(Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        })
+    }
+
+    /// Builds a grid from row-major `cells`
+    ///
+    /// Fails if `cells` can't be split into rows of `width`.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Result<Self, ParseError> {
+        if width == 0 || cells.is_empty() {
+            return Err(ParseError::new("empty grid"));
+        }
+        if !cells.len().is_multiple_of(width) {
+            return Err(ParseError::new(format!(
+                "last line has {} cells, expected {}",
+                cells.len() % width,
+                width
+            )));
+        }
+        Ok(Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    /// Builds a grid from a list of equally long rows
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut cells = Vec::new();
+        for (line, row) in rows.into_iter().enumerate() {
+            let expected = *width.get_or_insert(row.len());
+            if row.len() != expected {
+                return Err(ParseError::new(format!(
+                    "line {} has {} cells, expected {}",
+                    line + 1,
+                    row.len(),
+                    expected
+                )));
+            }
+            cells.extend(row);
+        }
+        Self::from_vec(width.unwrap_or_default(), cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, index: impl GridIndex) -> bool {
+        index.coordinate(self.width, self.height).is_some()
+    }
+
+    pub fn get(&self, index: impl GridIndex) -> Option<&T> {
+        let c = index.coordinate(self.width, self.height)?;
+        self.cells.get(c.line * self.width + c.column)
+    }
+
+    pub fn get_mut(&mut self, index: impl GridIndex) -> Option<&mut T> {
+        let c = index.coordinate(self.width, self.height)?;
+        self.cells.get_mut(c.line * self.width + c.column)
+    }
+
+    /// All cells in row-major order
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator {
+        self.cells.iter_mut()
+    }
+
+    /// All cells in row-major order together with their coordinate
+    pub fn indexed_iter(&self) -> impl DoubleEndedIterator<Item = (Coordinate, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(idx, cell)| (Coordinate::new(idx % width, idx / width), cell))
+    }
+
+    /// All coordinates in row-major order
+    pub fn coordinates(&self) -> impl DoubleEndedIterator<Item = Coordinate> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |idx| Coordinate::new(idx % width, idx / width))
+    }
+
+    pub fn row(&self, line: usize) -> &[T] {
+        &self.cells[line * self.width..(line + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, line: usize) -> &mut [T] {
+        &mut self.cells[line * self.width..(line + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> std::slice::Chunks<'_, T> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn rows_mut(&mut self) -> std::slice::ChunksMut<'_, T> {
+        self.cells.chunks_mut(self.width)
+    }
+
+    /// Cells of a single column from top to bottom
+    pub fn column(&self, column: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(column < self.width, "column {column} out of bounds");
+        self.cells[column..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    /// Orthogonal neighbours of `c` which lie inside the grid
+    pub fn neighbours4(&self, c: Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+        self.neighbours(c, Direction::ALL.map(Direction::offset))
+    }
+
+    /// Orthogonal and diagonal neighbours of `c` which lie inside the grid
+    pub fn neighbours8(&self, c: Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+        self.neighbours(c, Direction8::ALL.map(Direction8::offset))
+    }
+
+    fn neighbours<const N: usize>(
+        &self,
+        c: Coordinate,
+        offsets: [Point; N],
+    ) -> impl Iterator<Item = Coordinate> + '_ {
+        offsets
+            .into_iter()
+            .filter_map(move |offset| (Point::from(c) + offset).coordinate(self.width, self.height))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn map_indexed<U>(&self, mut f: impl FnMut(Coordinate, &T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.indexed_iter().map(|(c, cell)| f(c, cell)).collect(),
+        }
+    }
+
+    /// Builds a new grid of `width` x `height` by looking up every cell in `self`
+    fn remap(&self, width: usize, height: usize, from: impl Fn(usize, usize) -> usize) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|line| (0..width).map(move |column| (column, line)))
+            .map(|(column, line)| self.cells[from(column, line)].clone())
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Mirrors along the main diagonal, lines become columns
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, self.width, |column, line| {
+            column * width + line
+        })
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let (width, height) = (self.width, self.height);
+        self.remap(height, width, |column, line| {
+            (height - 1 - column) * width + line
+        })
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let (width, height) = (self.width, self.height);
+        self.remap(height, width, |column, line| {
+            column * width + (width - 1 - line)
+        })
+    }
+
+    /// Mirrors left to right
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.width, self.height, |column, line| {
+            line * width + (width - 1 - column)
+        })
+    }
+
+    /// Mirrors top to bottom
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let (width, height) = (self.width, self.height);
+        self.remap(width, height, |column, line| {
+            (height - 1 - line) * width + column
+        })
+    }
+
+    /// Displays the grid using `f` to render every cell
+    pub fn display_with<'a, D: Display>(&'a self, f: impl Fn(&T) -> D + 'a) -> impl Display + 'a {
+        DisplayWith { grid: self, f }
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    /// Parses one line per row, converting every character into a cell
+    ///
+    /// ```
+    /// use aoc_grid::{Coordinate, Grid};
+    /// let grid: Grid<char> = Grid::parse("ab\ncd\n").unwrap();
+    /// assert_eq!((2, 2), (grid.width(), grid.height()));
+    /// assert_eq!('c', grid[Coordinate::new(0, 1)]);
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut cells = Vec::new();
+        for (line, row) in input.trim_end_matches(['\r', '\n']).lines().enumerate() {
+            let mut len = 0;
+            for (column, found) in row.chars().enumerate() {
+                let cell = T::try_from(found).map_err(|err| {
+                    let message = format!("invalid cell {found:?}: {err}");
+                    ParseError::at_position(input, line, column, message)
+                })?;
+                cells.push(cell);
+                len += 1;
+            }
+            let expected = *width.get_or_insert(len);
+            if len != expected {
+                let message = format!("line has {len} cells, expected {expected}");
+                return Err(ParseError::at_position(
+                    input,
+                    line,
+                    len.min(expected),
+                    message,
+                ));
+            }
+        }
+        Self::from_vec(width.unwrap_or_default(), cells)
+    }
+}
+
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl<T, I: GridIndex> Index<I> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: I) -> &Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get(index)
+            .unwrap_or_else(|| panic!("index out of bounds of {width}x{height} grid"))
+    }
+}
+
+impl<T, I: GridIndex> IndexMut<I> for Grid<T> {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(index)
+            .unwrap_or_else(|| panic!("index out of bounds of {width}x{height} grid"))
+    }
+}
+
+/// One line per row, without trailing newline
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_rows(self, f, |cell, f| write!(f, "{cell}"))
+    }
+}
+
+struct DisplayWith<'a, T, F> {
+    grid: &'a Grid<T>,
+    f: F,
+}
+
+impl<T, D, F> Display for DisplayWith<'_, T, F>
+where
+    D: Display,
+    F: Fn(&T) -> D,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_rows(self.grid, f, |cell, f| write!(f, "{}", (self.f)(cell)))
+    }
+}
+
+fn write_rows<T>(
+    grid: &Grid<T>,
+    f: &mut fmt::Formatter<'_>,
+    cell: impl Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    for (idx, row) in grid.rows().enumerate() {
+        if idx > 0 {
+            writeln!(f)?;
+        }
+        for c in row {
+            cell(c, f)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef").unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = sample();
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!('f', grid[(2, 1)]);
+        assert_eq!("abc\ndef", grid.to_string());
+        assert_eq!(grid, "abc\r\ndef\r\n".parse().unwrap());
+    }
+
+    #[test]
+    fn test_empty() {
+        for (width, height) in [(0, 3), (3, 0), (0, 0)] {
+            assert_eq!(
+                Err(ParseError::new("empty grid")),
+                Grid::new(width, height, '.')
+            );
+        }
+        assert_eq!(
+            Err(ParseError::new("empty grid")),
+            Grid::<char>::from_vec(3, Vec::new())
+        );
+        let grid = Grid::new(3, 2, '.').unwrap();
+        assert_eq!(2, grid.rows().count());
+    }
+
+    #[rstest]
+    #[case("", "empty grid")]
+    #[case(
+        "ab\nc",
+        "line has 1 cells, expected 2 at line 2, column 2\n  |\n2 | c\n  |  ^"
+    )]
+    #[case(
+        "ab\ncde",
+        "line has 3 cells, expected 2 at line 2, column 3\n  |\n2 | cde\n  |   ^"
+    )]
+    fn test_parse_errors(#[case] input: &str, #[case] expected: &str) {
+        let error = Grid::<char>::parse(input).unwrap_err();
+        assert_eq!(expected, error.to_string());
+    }
+
+    #[test]
+    fn test_parse_invalid_cell() {
+        #[derive(Debug)]
+        struct Digit(u32);
+        impl TryFrom<char> for Digit {
+            type Error = &'static str;
+            fn try_from(value: char) -> Result<Self, Self::Error> {
+                value.to_digit(10).map(Digit).ok_or("not a digit")
+            }
+        }
+        let err = Grid::<Digit>::parse("12\n3x").unwrap_err();
+        assert_eq!("invalid cell 'x': not a digit", err.message);
+        let location = err.location.unwrap();
+        assert_eq!((2, 2), (location.line, location.column));
+        assert_eq!(7, Grid::<Digit>::parse("7").unwrap()[(0, 0)].0);
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = sample();
+        assert_eq!(['d', 'e', 'f'], grid.row(1));
+        assert_eq!(vec![&'b', &'e'], grid.column(1).collect::<Vec<_>>());
+        assert_eq!(3, grid.columns().count());
+        assert_eq!(2, grid.rows().count());
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid = sample();
+        assert_eq!("ad\nbe\ncf", grid.transpose().to_string());
+        assert_eq!("da\neb\nfc", grid.rotate_clockwise().to_string());
+        assert_eq!("cf\nbe\nad", grid.rotate_counter_clockwise().to_string());
+        assert_eq!("cba\nfed", grid.flip_horizontal().to_string());
+        assert_eq!("def\nabc", grid.flip_vertical().to_string());
+        assert_eq!(
+            grid,
+            grid.rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = sample();
+        let corner = Coordinate::new(0, 0);
+        assert_eq!(
+            vec![Coordinate::new(0, 1), Coordinate::new(1, 0)],
+            grid.neighbours4(corner).collect::<Vec<_>>()
+        );
+        assert_eq!(3, grid.neighbours8(corner).count());
+        assert_eq!(5, grid.neighbours8(Coordinate::new(1, 0)).count());
+    }
+
+    #[test]
+    fn test_signed_access() {
+        let mut grid = sample();
+        assert_eq!(None, grid.get(Point::new(-1, 0)));
+        assert_eq!(Some(&'e'), grid.get(Point::new(1, 1)));
+        grid[Point::new(0, 0)] = 'z';
+        assert_eq!("zbc\ndef", grid.to_string());
+        assert_eq!(
+            "123\n123",
+            grid.map_indexed(|c, _| c.column + 1).to_string()
+        );
+    }
+}
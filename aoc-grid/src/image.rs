@@ -0,0 +1,245 @@
+//! Frames as PNG or SVG pictures
+//!
+//! Every cell becomes a square of `scale` pixels on a black background. PNGs
+//! draw pipes, mirrors and rocks as simple shapes instead of glyphs, so no font
+//! is needed. SVGs keep the glyphs as text. Both are deterministic: the same
+//! frame always gives the same bytes.
+use crate::render::{Frame, Rgb};
+use std::{fmt::Write, io, path::Path};
+
+const BACKGROUND: Rgb = Rgb::BLACK;
+
+/// File format, chosen by extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Png,
+    Svg,
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "png" => Some(Format::Png),
+            "svg" => Some(Format::Svg),
+            _ => None,
+        }
+    }
+}
+
+/// Writes `frame` to `path`, as PNG or SVG depending on the extension
+pub fn save(frame: &Frame, path: &Path, scale: u32) -> io::Result<()> {
+    let contents = match Format::from_path(path) {
+        Some(Format::Png) => png(frame, scale),
+        Some(Format::Svg) => svg(frame, scale).into_bytes(),
+        None => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} is neither .png nor .svg", path.display()),
+            ))
+        }
+    };
+    std::fs::write(path, contents)
+}
+
+/// Whether pixel `x`, `y` of a `size` pixel cell belongs to `glyph`
+fn covers(glyph: char, x: u32, y: u32, size: u32) -> bool {
+    let mid = size / 2;
+    let half = (size / 8).max(1);
+    let band = |v: u32| v + half >= mid && v < mid + half;
+    let north = band(x) && y < mid + half;
+    let south = band(x) && y + half >= mid;
+    let west = band(y) && x < mid + half;
+    let east = band(y) && x + half >= mid;
+    match glyph {
+        ' ' => false,
+        '.' => band(x) && band(y),
+        '─' | '-' => west || east,
+        '│' | '|' => north || south,
+        '┌' | 'F' => south || east,
+        '┐' | '7' => south || west,
+        '└' | 'L' => north || east,
+        '┘' | 'J' => north || west,
+        '/' => (x + y).abs_diff(size - 1) < 2 * half,
+        '\\' => x.abs_diff(y) < 2 * half,
+        'O' => {
+            // doubled coordinates, so the centre of the cell is a whole number
+            let (dx, dy) = ((2 * x + 1).abs_diff(size), (2 * y + 1).abs_diff(size));
+            let radius = size * 3 / 4;
+            dx * dx + dy * dy <= radius * radius
+        }
+        _ => {
+            let inset = size / 8;
+            (inset..size - inset).contains(&x) && (inset..size - inset).contains(&y)
+        }
+    }
+}
+
+/// `frame` as PNG, `scale` pixels per cell
+pub fn png(frame: &Frame, scale: u32) -> Vec<u8> {
+    let scale = scale.max(1);
+    let (width, height) = (frame.width() as u32 * scale, frame.height() as u32 * scale);
+    let mut pixels = Vec::with_capacity(3 * (width * height) as usize);
+    for row in frame.rows() {
+        for y in 0..scale {
+            for cell in row {
+                let bg = cell.bg.unwrap_or(BACKGROUND);
+                for x in 0..scale {
+                    let Rgb(r, g, b) = if covers(cell.glyph, x, y, scale) {
+                        cell.fg
+                    } else {
+                        bg
+                    };
+                    pixels.extend([r, g, b]);
+                }
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    let mut encoder = ::png::Encoder::new(&mut out, width, height);
+    encoder.set_color(::png::ColorType::Rgb);
+    encoder.set_depth(::png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&pixels))
+        .expect("encoding into memory");
+    out
+}
+
+fn hex(Rgb(r, g, b): Rgb) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+/// `frame` as SVG, `scale` pixels per cell
+pub fn svg(frame: &Frame, scale: u32) -> String {
+    let scale = scale.max(1);
+    let (width, height) = (frame.width() as u32 * scale, frame.height() as u32 * scale);
+    let mut out = String::new();
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="monospace" font-size="{scale}" text-anchor="middle" dominant-baseline="central">"#
+    )
+    .expect("writing to a String");
+    writeln!(
+        out,
+        r#"<rect width="{width}" height="{height}" fill="{}"/>"#,
+        hex(BACKGROUND)
+    )
+    .expect("writing to a String");
+    for (position, cell) in frame.indexed_iter() {
+        let (x, y) = (position.column as u32 * scale, position.line as u32 * scale);
+        if let Some(bg) = cell.bg {
+            writeln!(
+                out,
+                r#"<rect x="{x}" y="{y}" width="{scale}" height="{scale}" fill="{}"/>"#,
+                hex(bg)
+            )
+            .expect("writing to a String");
+        }
+        let glyph = match cell.glyph {
+            ' ' => continue,
+            '&' => "&amp;".to_string(),
+            '<' => "&lt;".to_string(),
+            '>' => "&gt;".to_string(),
+            glyph => glyph.to_string(),
+        };
+        // `scale / 2` rounded up, so the text is centred on odd scales too
+        let (cx, cy) = (x + scale.div_ceil(2), y + scale.div_ceil(2));
+        writeln!(
+            out,
+            r#"<text x="{cx}" y="{cy}" fill="{}">{glyph}</text>"#,
+            hex(cell.fg)
+        )
+        .expect("writing to a String");
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{render::Cell, Grid};
+
+    fn frame() -> Frame {
+        Grid::from_vec(
+            2,
+            vec![
+                Cell::new('─', Rgb::GREEN),
+                Cell::new('<', Rgb::YELLOW).on(Rgb::BLUE),
+                Cell::default(),
+                Cell::new('#', Rgb::WHITE),
+            ],
+        )
+        .expect("two rows")
+    }
+
+    #[test]
+    fn test_covers() {
+        let picture = |glyph| -> Vec<String> {
+            (0..8)
+                .map(|y| {
+                    (0..8)
+                        .map(|x| if covers(glyph, x, y, 8) { '#' } else { '.' })
+                        .collect()
+                })
+                .collect()
+        };
+        assert_eq!(
+            [
+                "........", "........", "........", "...#####", "...#####", "...##...", "...##...",
+                "...##...",
+            ]
+            .to_vec(),
+            picture('┌')
+        );
+        assert_eq!("...##...", picture('.')[4]);
+        assert_eq!("........", picture('.')[5]);
+        assert_eq!(".######.", picture('#')[1]);
+        assert!(picture(' ').iter().all(|row| row == "........"));
+    }
+
+    #[test]
+    fn test_png() {
+        let png = png(&frame(), 8);
+        let decoder = ::png::Decoder::new(png.as_slice());
+        let mut reader = decoder.read_info().expect("valid PNG");
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).expect("one image");
+        assert_eq!((16, 16), (info.width, info.height));
+        let pixel = |x: usize, y: usize| {
+            let idx = 3 * (y * 16 + x);
+            Rgb(pixels[idx], pixels[idx + 1], pixels[idx + 2])
+        };
+        // the pipe through the first cell, the arrow on its background in the second
+        assert_eq!(Rgb::GREEN, pixel(0, 3));
+        assert_eq!(BACKGROUND, pixel(0, 0));
+        assert_eq!(Rgb::BLUE, pixel(8, 0));
+        assert_eq!(Rgb::YELLOW, pixel(12, 4));
+        assert_eq!(BACKGROUND, pixel(4, 12));
+        assert_eq!(Rgb::WHITE, pixel(12, 12));
+        assert_eq!(png, super::png(&frame(), 8));
+    }
+
+    #[test]
+    fn test_svg() {
+        assert_eq!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="20" height="20" viewBox="0 0 20 20" font-family="monospace" font-size="10" text-anchor="middle" dominant-baseline="central">
+<rect width="20" height="20" fill="#000000"/>
+<text x="5" y="5" fill="#64be3c">─</text>
+<rect x="10" y="0" width="10" height="10" fill="#268bd2"/>
+<text x="15" y="5" fill="#fac828">&lt;</text>
+<text x="15" y="15" fill="#ffffff">#</text>
+</svg>
+"##,
+            svg(&frame(), 10)
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(Some(Format::Png), Format::from_path(Path::new("a/b.png")));
+        assert_eq!(Some(Format::Svg), Format::from_path(Path::new("b.svg")));
+        assert_eq!(None, Format::from_path(Path::new("b.jpg")));
+    }
+}
@@ -0,0 +1,13 @@
+pub mod coordinate;
+pub mod direction;
+pub mod grid;
+pub mod image;
+pub mod render;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+
+pub use coordinate::{Coordinate, GridIndex, Point};
+pub use direction::{Direction, Direction8, InvalidDelta};
+pub use grid::Grid;
+#[cfg(feature = "proptest")]
+pub use strategy::grid_strategy;
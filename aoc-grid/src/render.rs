@@ -0,0 +1,154 @@
+//! Grid states as coloured cells
+//!
+//! Days with a visualisation turn each step of their solution into a [`Frame`],
+//! the runner shows them one after another in the terminal with [`ansi`]. A
+//! still picture of the result can be saved with [`crate::image`].
+use crate::Grid;
+use aoc_core::ParseError;
+use std::fmt::Write;
+
+/// 24 bit colour
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GREY: Rgb = Rgb(110, 110, 110);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(100, 190, 60);
+    pub const BLUE: Rgb = Rgb(38, 139, 210);
+    pub const YELLOW: Rgb = Rgb(250, 200, 40);
+
+    /// Blends towards `other`, `0.0` is `self` and `1.0` is `other`
+    ///
+    /// ```
+    /// use aoc_grid::render::Rgb;
+    /// assert_eq!(Rgb(128, 64, 0), Rgb::BLACK.mix(Rgb(255, 128, 0), 0.5));
+    /// ```
+    pub fn mix(self, other: Rgb, amount: f32) -> Rgb {
+        let amount = amount.clamp(0.0, 1.0);
+        let channel = |from: u8, to: u8| {
+            (f32::from(from) + (f32::from(to) - f32::from(from)) * amount).round() as u8
+        };
+        Rgb(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+}
+
+/// A glyph in a colour, optionally on a background
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub fg: Rgb,
+    /// `None` keeps the background of the terminal
+    pub bg: Option<Rgb>,
+}
+
+impl Cell {
+    pub const fn new(glyph: char, fg: Rgb) -> Self {
+        Self {
+            glyph,
+            fg,
+            bg: None,
+        }
+    }
+
+    pub const fn on(self, bg: Rgb) -> Self {
+        Self {
+            bg: Some(bg),
+            ..self
+        }
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self::new(' ', Rgb::WHITE)
+    }
+}
+
+/// One picture of a grid state
+pub type Frame = Grid<Cell>;
+
+/// The steps of a visualisation
+pub type Frames = Box<dyn Iterator<Item = Frame>>;
+
+/// Visualisation of one day
+#[derive(Clone, Copy)]
+pub struct Visualizer {
+    pub day: u8,
+    frames: fn(&str) -> Result<Frames, ParseError>,
+    picture: fn(&str, usize) -> Result<Frame, ParseError>,
+}
+
+impl Visualizer {
+    pub fn new(
+        day: u8,
+        frames: fn(&str) -> Result<Frames, ParseError>,
+        picture: fn(&str, usize) -> Result<Frame, ParseError>,
+    ) -> Self {
+        Self {
+            day,
+            frames,
+            picture,
+        }
+    }
+
+    /// Parses `input` and returns the frames, which are computed as they are taken
+    pub fn frames(&self, input: &str) -> Result<Frames, ParseError> {
+        (self.frames)(input)
+    }
+
+    /// Still picture of the result
+    ///
+    /// `steps` is how far to go where a day can go on, e.g. the spin cycles of day 14.
+    /// Other days ignore it.
+    pub fn picture(&self, input: &str, steps: usize) -> Result<Frame, ParseError> {
+        (self.picture)(input, steps)
+    }
+}
+
+/// `frame` with 24 bit colour escape codes, one line per row
+///
+/// Colours are only switched where they change and reset at the end of every line.
+pub fn ansi(frame: &Frame) -> String {
+    let mut out = String::new();
+    for row in frame.rows() {
+        let mut current = None;
+        for cell in row {
+            if current != Some((cell.fg, cell.bg)) {
+                let Rgb(r, g, b) = cell.fg;
+                write!(out, "\x1b[0;38;2;{};{};{}", r, g, b).expect("writing to a String");
+                if let Some(Rgb(r, g, b)) = cell.bg {
+                    write!(out, ";48;2;{};{};{}", r, g, b).expect("writing to a String");
+                }
+                out.push('m');
+                current = Some((cell.fg, cell.bg));
+            }
+            out.push(cell.glyph);
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ansi() {
+        let red = Cell::new('#', Rgb::RED);
+        let frame = Grid::from_vec(3, vec![red, red, Cell::new('.', Rgb::GREY).on(Rgb::BLUE)])
+            .expect("one row");
+        assert_eq!(
+            "\x1b[0;38;2;220;50;47m##\x1b[0;38;2;110;110;110;48;2;38;139;210m.\x1b[0m\n",
+            ansi(&frame)
+        );
+        assert_eq!("##.", frame.display_with(|cell| cell.glyph).to_string());
+    }
+}
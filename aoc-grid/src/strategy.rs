@@ -0,0 +1,16 @@
+//! Proptest strategies for grids
+use proptest::{collection, strategy::Strategy};
+use std::fmt::Debug;
+
+use crate::grid::Grid;
+
+/// Grids of 1 to `max_side` cells per side, each drawn from `cell`
+pub fn grid_strategy<T: Clone + Debug>(
+    cell: impl Strategy<Value = T> + Clone,
+    max_side: usize,
+) -> impl Strategy<Value = Grid<T>> {
+    (1..=max_side, 1..=max_side).prop_flat_map(move |(width, height)| {
+        collection::vec(cell.clone(), width * height)
+            .prop_map(move |cells| Grid::from_vec(width, cells).expect("not empty"))
+    })
+}
@@ -0,0 +1,83 @@
+//! Parse and solve benchmarks for every registered day
+//!
+//! Runs against the personal inputs. Days without one use a generated input
+//! (seed 0, default size) instead and show up as `day-XX/partN/generated`.
+//! Filter with e.g. `cargo bench -p aoc --bench days -- day-05/part2`.
+use aoc::days::{self, Visitor};
+use aoc_core::{
+    input::{Inputs, DEFAULT_ID},
+    Solution,
+};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use std::{panic, time::Duration};
+
+struct Bench<'a> {
+    criterion: &'a mut Criterion,
+    inputs: Inputs,
+}
+
+impl Visitor for Bench<'_> {
+    fn visit<S: Solution>(&mut self) {
+        let mut name = format!("day-{:02}/part{}", S::DAY, S::PART);
+        let input = match self.inputs.try_load(S::DAY, DEFAULT_ID) {
+            Ok(Some(input)) => input,
+            Ok(None) => {
+                let Some(generator) = days::generator(S::DAY) else {
+                    eprintln!(
+                        "skipping: no input at {}",
+                        self.inputs.path(S::DAY, DEFAULT_ID).display()
+                    );
+                    return;
+                };
+                name.push_str("/generated");
+                match panic::catch_unwind(|| generator.generate(0, generator.default_size)) {
+                    Ok(input) => input,
+                    Err(_) => {
+                        eprintln!("skipping day {}: generator panicked", S::DAY);
+                        return;
+                    }
+                }
+            }
+            Err(e) => panic!("{:#}", e),
+        };
+        // check once so a broken solution doesn't get benchmarked, nor one which panics like
+        // the `todo!()` of a fresh day
+        match panic::catch_unwind(|| S::process(&input)) {
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) => {
+                eprintln!("skipping day {} part {}: {:#}", S::DAY, S::PART, e);
+                return;
+            }
+            Err(_) => {
+                eprintln!("skipping day {} part {}: panicked", S::DAY, S::PART);
+                return;
+            }
+        }
+
+        let mut group = self.criterion.benchmark_group(name);
+        // some days take seconds per iteration
+        group.sample_size(10);
+        group.measurement_time(Duration::from_secs(5));
+        group.bench_function("parse", |b| {
+            b.iter(|| S::parse(black_box(&input)).expect("checked above"))
+        });
+        group.bench_function("solve", |b| {
+            b.iter_batched(
+                || S::parse(&input).expect("checked above"),
+                |parsed| S::solve(black_box(parsed)).expect("checked above"),
+                BatchSize::LargeInput,
+            )
+        });
+        group.finish();
+    }
+}
+
+fn all_days(criterion: &mut Criterion) {
+    days::visit(&mut Bench {
+        criterion,
+        inputs: Inputs::from_env(),
+    });
+}
+
+criterion_group!(benches, all_days);
+criterion_main!(benches);
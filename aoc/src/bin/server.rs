@@ -0,0 +1,34 @@
+use anyhow::anyhow;
+use aoc::{days, server};
+use clap::Parser;
+use std::{net::SocketAddr, time::Duration};
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::EnvFilter;
+
+#[derive(Parser)]
+#[command(about = "Serves the Advent of Code 2023 solutions as JSON over HTTP")]
+struct Cli {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:8023")]
+    listen: SocketAddr,
+    /// Seconds a solve may take before the request is answered with 504
+    #[arg(long, default_value_t = server::DEADLINE.as_secs())]
+    deadline: u64,
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let filter = EnvFilter::builder()
+        .with_default_directive(LevelFilter::INFO.into())
+        .from_env_lossy();
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .init();
+
+    let server = tiny_http::Server::http(cli.listen)
+        .map_err(|e| anyhow!("listening on {}: {}", cli.listen, e))?;
+    tracing::info!("listening on http://{}", cli.listen);
+    server::serve(&server, &days::solvers(), Duration::from_secs(cli.deadline));
+    Ok(())
+}
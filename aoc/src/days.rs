@@ -0,0 +1,108 @@
+use aoc_core::{generate::Generator, Solution, Solver};
+use aoc_grid::render::Visualizer;
+
+/// Gets handed the concrete type of every registered part
+///
+/// Used where the type erased [`Solver`] isn't enough, e.g. the benchmarks.
+pub trait Visitor {
+    fn visit<S: Solution>(&mut self);
+}
+
+/// Registers every day crate with the runner
+///
+/// `aoc new` appends to this list, so keep one day per entry.
+macro_rules! days {
+    ($($day:ident),* $(,)?) => {
+        /// Calls `visitor` with both parts of every known day, ordered by day and part
+        pub fn visit(visitor: &mut impl Visitor) {
+            $(
+                visitor.visit::<$day::part1::Part1>();
+                visitor.visit::<$day::part2::Part2>();
+            )*
+        }
+
+        /// Input generators of every known day, ordered by day
+        pub fn generators() -> Vec<Generator> {
+            vec![$(
+                Generator::new(
+                    <$day::part1::Part1 as Solution>::DAY,
+                    $day::generate::DEFAULT_SIZE,
+                    $day::generate::generate,
+                ),
+            )*]
+        }
+    };
+}
+
+days![
+    day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09, day_10, day_11, day_12,
+    day_13, day_14, day_15, day_16,
+];
+
+/// Generator of `day`, if it is known
+pub fn generator(day: u8) -> Option<Generator> {
+    generators()
+        .into_iter()
+        .find(|generator| generator.day == day)
+}
+
+/// Visualisations of the days which have one, ordered by day
+pub fn visualizers() -> Vec<Visualizer> {
+    vec![
+        Visualizer::new(10, day_10::visualize::frames, day_10::visualize::picture),
+        Visualizer::new(11, day_11::visualize::frames, day_11::visualize::picture),
+        Visualizer::new(14, day_14::visualize::frames, day_14::visualize::picture),
+        Visualizer::new(16, day_16::visualize::frames, day_16::visualize::heatmap),
+    ]
+}
+
+/// Visualisation of `day`, if it has one
+pub fn visualizer(day: u8) -> Option<Visualizer> {
+    visualizers()
+        .into_iter()
+        .find(|visualizer| visualizer.day == day)
+}
+
+/// Both parts of every known day, ordered by day and part
+pub fn solvers() -> Vec<Solver> {
+    struct Collect(Vec<Solver>);
+
+    impl Visitor for Collect {
+        fn visit<S: Solution>(&mut self) {
+            self.0.push(Solver::new::<S>());
+        }
+    }
+
+    let mut collect = Collect(Vec::new());
+    visit(&mut collect);
+    collect.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solvers_are_ordered() {
+        let solvers = solvers();
+        assert!(solvers
+            .windows(2)
+            .all(|w| (w[0].day, w[0].part) < (w[1].day, w[1].part)));
+        assert!(solvers.iter().all(|s| s.part == 1 || s.part == 2));
+    }
+
+    #[test]
+    fn test_generators_match_solvers() {
+        let days: Vec<u8> = generators().iter().map(|g| g.day).collect();
+        let mut solver_days: Vec<u8> = solvers().iter().map(|s| s.day).collect();
+        solver_days.dedup();
+        assert_eq!(days, solver_days);
+    }
+
+    #[test]
+    fn test_visualizers_are_ordered() {
+        let days: Vec<u8> = visualizers().iter().map(|v| v.day).collect();
+        assert!(days.windows(2).all(|w| w[0] < w[1]));
+        assert!(days.iter().all(|day| generator(*day).is_some()));
+    }
+}
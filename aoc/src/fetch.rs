@@ -0,0 +1,302 @@
+//! Downloads puzzle inputs into the input directory
+//!
+//! Inputs are personal, so they need the `session` cookie of a logged in
+//! browser. Downloaded inputs are stored like hand copied ones and are never
+//! requested again. An empty file, like the placeholder of `aoc new`, counts as
+//! missing.
+use anyhow::{bail, Context};
+use aoc_core::input::{Inputs, DEFAULT_ID};
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+/// Environment variable holding the session token
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Puzzles of this year
+pub const BASE_URL: &str = "https://adventofcode.com/2023";
+/// Minimum time between two downloads, the site asks not to hammer it
+pub const MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Sent with every request so the site knows whom to contact
+const USER_AGENT: &str = "github.com/jschpp/aoc-2023 input fetcher";
+/// File in the input directory remembering the last download, so separate runs are throttled too
+const LAST_FETCH: &str = ".last-fetch";
+
+/// Transport inputs are downloaded with
+///
+/// [`Ureq`] talks to the site, tests hand in a fixture or a local server.
+pub trait Http {
+    /// Body of `GET url`, sent with the session cookie
+    fn get(&self, url: &str, session: &str) -> anyhow::Result<String>;
+}
+
+/// Blocking HTTPS client
+#[derive(Debug, Clone)]
+pub struct Ureq(ureq::Agent);
+
+impl Default for Ureq {
+    fn default() -> Self {
+        Self(
+            ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        )
+    }
+}
+
+impl Http for Ureq {
+    fn get(&self, url: &str, session: &str) -> anyhow::Result<String> {
+        let response = self
+            .0
+            .get(url)
+            .set("Cookie", &format!("session={}", session))
+            .call();
+        match response {
+            Ok(response) => response.into_string().context("reading the response"),
+            Err(ureq::Error::Status(404, _)) => bail!("{} is not available (yet)", url),
+            Err(ureq::Error::Status(400 | 401, _)) => {
+                bail!("{} refused the session token, it may have expired", url)
+            }
+            Err(e) => Err(e).with_context(|| format!("requesting {}", url)),
+        }
+    }
+}
+
+/// Where an input came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// It was already there
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads inputs which aren't stored yet
+#[derive(Debug)]
+pub struct Fetcher<H> {
+    http: H,
+    inputs: Inputs,
+    session: Option<String>,
+    base_url: String,
+    interval: Duration,
+}
+
+impl<H: Http> Fetcher<H> {
+    /// Fetcher for the real site, `session` is only needed once something has to be downloaded
+    pub fn new(http: H, inputs: Inputs, session: Option<String>) -> Self {
+        Self {
+            http,
+            inputs,
+            session,
+            base_url: BASE_URL.to_string(),
+            interval: MIN_INTERVAL,
+        }
+    }
+
+    /// Downloads from somewhere else, e.g. a local server
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Makes sure the input of `day` is stored, downloading it if it isn't or if it is blank
+    pub fn fetch(&self, day: u8) -> anyhow::Result<Fetched> {
+        let path = self.inputs.path(day, DEFAULT_ID);
+        if let Some(input) = self.inputs.try_load(day, DEFAULT_ID)? {
+            if !input.trim().is_empty() {
+                return Ok(Fetched::Cached(path));
+            }
+        }
+        let Some(session) = &self.session else {
+            bail!(
+                "no input for day {} and no session token, set {} to the `session` cookie of adventofcode.com",
+                day,
+                SESSION_ENV
+            );
+        };
+
+        self.throttle()?;
+        let url = format!("{}/day/{}/input", self.base_url, day);
+        let input = self.http.get(&url, session);
+        self.write(
+            &self.inputs.dir().join(LAST_FETCH),
+            &now()?.as_micros().to_string(),
+        )?;
+        let input = input?;
+        if input.trim().is_empty() {
+            bail!("{} sent an empty input", url);
+        }
+
+        self.write(&path, &input)?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// Waits until `interval` has passed since the last download
+    fn throttle(&self) -> anyhow::Result<()> {
+        let last = match std::fs::read_to_string(self.inputs.dir().join(LAST_FETCH)) {
+            Ok(micros) => micros.trim().parse().map(Duration::from_micros).ok(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e).context("reading the time of the last download"),
+        };
+        if let Some(last) = last {
+            let wait = (last + self.interval).saturating_sub(now()?);
+            std::thread::sleep(wait);
+        }
+        Ok(())
+    }
+
+    fn write(&self, path: &Path, contents: &str) -> anyhow::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+        }
+        std::fs::write(path, contents).with_context(|| format!("writing {}", path.display()))
+    }
+}
+
+fn now() -> anyhow::Result<Duration> {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .context("system clock is before 1970")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        cell::RefCell,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        time::Instant,
+    };
+
+    /// Serves `<dir>/day-X.txt` for `.../day/X/input` and remembers the requests
+    struct Fixtures {
+        dir: PathBuf,
+        requests: RefCell<Vec<String>>,
+    }
+
+    impl Http for Fixtures {
+        fn get(&self, url: &str, _session: &str) -> anyhow::Result<String> {
+            self.requests.borrow_mut().push(url.to_string());
+            let day = url
+                .trim_end_matches("/input")
+                .rsplit('/')
+                .next()
+                .context("no day")?;
+            let path = self.dir.join(format!("day-{}.txt", day));
+            std::fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))
+        }
+    }
+
+    fn temp_dir(name: &str) -> anyhow::Result<PathBuf> {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        Ok(dir)
+    }
+
+    #[test]
+    fn test_fetch_caches() -> anyhow::Result<()> {
+        let dir = temp_dir("cache")?;
+        std::fs::write(dir.join("day-1.txt"), "1abc2\n")?;
+        let fixtures = Fixtures {
+            dir: dir.clone(),
+            requests: RefCell::new(Vec::new()),
+        };
+        let inputs = Inputs::new(dir.join("inputs"));
+        let fetcher = Fetcher::new(fixtures, inputs.clone(), Some("token".to_string()))
+            .with_base_url("fixture")
+            .with_interval(Duration::from_millis(100));
+
+        let start = Instant::now();
+        let first = fetcher.fetch(1);
+        let second = fetcher.fetch(1);
+        let missing = fetcher.fetch(2);
+        let third = fetcher.fetch(1);
+        let elapsed = start.elapsed();
+        let input = inputs.try_load(1, DEFAULT_ID);
+        let stored = inputs.try_load(2, DEFAULT_ID);
+        std::fs::remove_dir_all(&dir)?;
+
+        let path = inputs.path(1, DEFAULT_ID);
+        assert_eq!(Fetched::Downloaded(path.clone()), first?);
+        assert_eq!(Fetched::Cached(path.clone()), second?);
+        assert!(missing.is_err());
+        assert_eq!(Fetched::Cached(path), third?);
+        assert_eq!(
+            vec!["fixture/day/1/input", "fixture/day/2/input"],
+            *fetcher.http.requests.borrow()
+        );
+        // the failed download still had to wait
+        assert!(elapsed >= Duration::from_millis(100));
+        assert_eq!(Some("1abc2\n".to_string()), input?);
+        assert_eq!(None, stored?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_needs_session() -> anyhow::Result<()> {
+        let dir = temp_dir("session")?;
+        let fixtures = Fixtures {
+            dir: dir.clone(),
+            requests: RefCell::new(Vec::new()),
+        };
+        let fetcher = Fetcher::new(fixtures, Inputs::new(&dir), None);
+        let result = fetcher.fetch(1);
+        std::fs::remove_dir_all(&dir)?;
+
+        let error = result.expect_err("no session").to_string();
+        assert!(error.contains(SESSION_ENV));
+        assert!(fetcher.http.requests.borrow().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ureq_local_server() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let address = listener.local_addr()?;
+        let server = std::thread::spawn(move || -> std::io::Result<Vec<String>> {
+            let mut requests = Vec::new();
+            for _ in 0..2 {
+                let (stream, _) = listener.accept()?;
+                let mut reader = BufReader::new(stream.try_clone()?);
+                let mut request = String::new();
+                while reader.read_line(&mut request)? > 2 {}
+                let response = if request.starts_with("GET /day/1/input ") {
+                    "HTTP/1.1 200 OK\r\nContent-Length: 6\r\nConnection: close\r\n\r\n1abc2\n"
+                } else {
+                    "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                };
+                (&stream).write_all(response.as_bytes())?;
+                requests.push(request);
+            }
+            Ok(requests)
+        });
+
+        let dir = temp_dir("ureq")?;
+        let inputs = Inputs::new(&dir);
+        let fetcher = Fetcher::new(Ureq::default(), inputs.clone(), Some("token".to_string()))
+            .with_base_url(format!("http://{}", address))
+            .with_interval(Duration::ZERO);
+        let found = fetcher.fetch(1);
+        let missing = fetcher.fetch(25);
+        let input = inputs.try_load(1, DEFAULT_ID);
+        std::fs::remove_dir_all(&dir)?;
+
+        assert!(matches!(found?, Fetched::Downloaded(_)));
+        assert!(missing
+            .expect_err("no input")
+            .to_string()
+            .contains("not available"));
+        assert_eq!(Some("1abc2\n".to_string()), input?);
+        let requests = server.join().expect("server panicked")?;
+        assert!(requests[0].contains("Cookie: session=token\r\n"));
+        assert!(requests[0].contains(USER_AGENT));
+        Ok(())
+    }
+}
@@ -0,0 +1,471 @@
+//! Timings of every run, kept in a local file to spot regressions
+//!
+//! Each solved part appends one line to `timings.tsv` in the workspace root
+//! (override with `AOC_HISTORY`): unix time, day, part, input id, build, parse
+//! and solve nanoseconds, separated by tabs.
+//!
+//! Debug and release builds are kept apart, they are never compared with each other.
+use crate::table;
+use anyhow::{bail, Context};
+use aoc_core::input::workspace_root;
+use std::{
+    fmt,
+    fs::OpenOptions,
+    io::Write,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, SystemTime},
+};
+
+/// Environment variable overriding the location of the history
+pub const HISTORY_ENV: &str = "AOC_HISTORY";
+/// File name of the history in the workspace root
+pub const HISTORY_FILE: &str = "timings.tsv";
+/// Slowdowns smaller than this are noise and never reported
+pub const NOISE: Duration = Duration::from_millis(1);
+/// Days of a season, the budget is shared between them
+pub const SEASON: u8 = 25;
+
+const HEADER: &str = "# unix time\tday\tpart\tinput\tbuild\tparse ns\tsolve ns";
+
+pub fn path_from_env() -> PathBuf {
+    match std::env::var_os(HISTORY_ENV) {
+        Some(path) => PathBuf::from(path),
+        None => workspace_root().join(HISTORY_FILE),
+    }
+}
+
+/// Profile the runner was built with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Build {
+    Debug,
+    Release,
+}
+
+impl Build {
+    pub const fn current() -> Self {
+        if cfg!(debug_assertions) {
+            Build::Debug
+        } else {
+            Build::Release
+        }
+    }
+}
+
+impl fmt::Display for Build {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Build::Debug => "debug",
+            Build::Release => "release",
+        })
+    }
+}
+
+impl FromStr for Build {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "debug" => Ok(Build::Debug),
+            "release" => Ok(Build::Release),
+            _ => bail!("unknown build `{}`, expected debug or release", s),
+        }
+    }
+}
+
+/// Timing of one solved part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// Seconds since the unix epoch
+    pub time: u64,
+    pub day: u8,
+    pub part: u8,
+    pub id: String,
+    pub build: Build,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Record {
+    /// A run which just finished in the current build
+    pub fn now(day: u8, part: u8, id: &str, parse: Duration, solve: Duration) -> Self {
+        let time = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs());
+        Self {
+            time,
+            day,
+            part,
+            id: id.to_string(),
+            build: Build::current(),
+            parse,
+            solve,
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+
+    fn same_solver(&self, other: &Record) -> bool {
+        (self.day, self.part, &self.id, self.build)
+            == (other.day, other.part, &other.id, other.build)
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.time,
+            self.day,
+            self.part,
+            self.id,
+            self.build,
+            self.parse.as_nanos(),
+            self.solve.as_nanos()
+        )
+    }
+}
+
+impl FromStr for Record {
+    type Err = anyhow::Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let [time, day, part, id, build, parse, solve] = fields[..] else {
+            bail!("expected 7 tab separated fields, got {}", fields.len());
+        };
+        let nanos = |field: &str| -> anyhow::Result<Duration> {
+            Ok(Duration::from_nanos(field.parse().with_context(|| {
+                format!("`{}` isn't a number of nanoseconds", field)
+            })?))
+        };
+        Ok(Self {
+            time: time.parse().context("time")?,
+            day: day.parse().context("day")?,
+            part: part.parse().context("part")?,
+            id: id.to_string(),
+            build: build.parse()?,
+            parse: nanos(parse)?,
+            solve: nanos(solve)?,
+        })
+    }
+}
+
+/// All recorded runs, oldest first
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    records: Vec<Record>,
+}
+
+impl History {
+    pub fn parse(input: &str) -> anyhow::Result<Self> {
+        let records = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .map(|(idx, line)| line.parse().with_context(|| format!("line {}", idx + 1)))
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self { records })
+    }
+
+    /// Reads the history, a missing file is an empty history
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(input) => Self::parse(&input).with_context(|| format!("parsing {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("reading {}", path.display())),
+        }
+    }
+
+    /// Appends `records` to the file at `path` and to `self`
+    pub fn append(&mut self, path: &Path, records: &[Record]) -> anyhow::Result<()> {
+        let mut lines = String::new();
+        if !path.exists() {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)
+                    .with_context(|| format!("creating {}", dir.display()))?;
+            }
+            lines.push_str(HEADER);
+            lines.push('\n');
+        }
+        for record in records {
+            lines.push_str(&record.to_string());
+            lines.push('\n');
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| file.write_all(lines.as_bytes()))
+            .with_context(|| format!("writing {}", path.display()))?;
+        self.records.extend_from_slice(records);
+        Ok(())
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Fastest recorded total of the same day, part, input and build
+    pub fn best(&self, record: &Record) -> Option<Duration> {
+        self.records
+            .iter()
+            .filter(|other| other.same_solver(record))
+            .map(Record::total)
+            .min()
+    }
+
+    /// The newest record of `day` and `part`
+    pub fn latest(&self, day: u8, part: u8, id: &str, build: Build) -> Option<&Record> {
+        self.records.iter().rev().find(|record| {
+            (record.day, record.part, record.id.as_str(), record.build) == (day, part, id, build)
+        })
+    }
+
+    /// How much slower `record` is than the best recorded run, if that's more
+    /// than `tolerance` percent and more than [`NOISE`]
+    pub fn regression(&self, record: &Record, tolerance: f64) -> Option<Regression> {
+        let best = self.best(record)?;
+        let total = record.total();
+        let percent = (total.as_secs_f64() / best.as_secs_f64() - 1.0) * 100.0;
+        (total > best + NOISE && percent > tolerance).then_some(Regression {
+            best,
+            total,
+            percent,
+        })
+    }
+}
+
+/// A run notably slower than the best recorded one
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Regression {
+    pub best: Duration,
+    pub total: Duration,
+    pub percent: f64,
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.0}% slower than the best run ({:.2?} instead of {:.2?})",
+            self.percent, self.total, self.best
+        )
+    }
+}
+
+/// Season table: the newest timing of every day against a total budget
+///
+/// Like the "all days in under a second" challenge, every day gets an equal
+/// share of `budget`, days exceeding it are marked.
+pub struct Report<'a> {
+    pub history: &'a History,
+    pub days: &'a [u8],
+    pub id: &'a str,
+    pub build: Build,
+    pub budget: Duration,
+}
+
+impl Report<'_> {
+    /// Budget of one day
+    pub fn share(&self) -> Duration {
+        self.budget / u32::from(SEASON)
+    }
+
+    /// Total of the parts which were run, `None` if neither was
+    fn day_total(&self, day: u8) -> Option<Duration> {
+        [1, 2]
+            .iter()
+            .filter_map(|&part| self.history.latest(day, part, self.id, self.build))
+            .map(Record::total)
+            .reduce(|a, b| a + b)
+    }
+
+    /// Days over their share of the budget
+    pub fn over(&self) -> Vec<u8> {
+        self.days
+            .iter()
+            .copied()
+            .filter(|&day| {
+                self.day_total(day)
+                    .is_some_and(|total| total > self.share())
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let timing =
+            |timing: Option<Duration>| timing.map_or("-".to_string(), |t| format!("{:.2?}", t));
+        let mut cumulative = Duration::ZERO;
+        let rows: Vec<[String; 6]> = self
+            .days
+            .iter()
+            .map(|&day| {
+                let part = |part| {
+                    self.history
+                        .latest(day, part, self.id, self.build)
+                        .map(Record::total)
+                };
+                let total = self.day_total(day);
+                cumulative += total.unwrap_or_default();
+                let status = match total {
+                    None => "missing",
+                    Some(total) if total > self.share() => "OVER",
+                    Some(_) => "ok",
+                };
+                [
+                    format!("{:02}", day),
+                    timing(part(1)),
+                    timing(part(2)),
+                    timing(total),
+                    format!("{:.2?}", cumulative),
+                    status.to_string(),
+                ]
+            })
+            .collect();
+        table::render(
+            f,
+            ["day", "part 1", "part 2", "total", "cumulative", "status"],
+            &rows,
+            &[0, 1, 2, 3, 4],
+        )?;
+        write!(
+            f,
+            "{} build, {:.2?} of {:.2?} ({:.2?} per day), ",
+            self.build,
+            cumulative,
+            self.budget,
+            self.share()
+        )?;
+        match self.over()[..] {
+            [] => write!(f, "every day within budget"),
+            ref over => write!(
+                f,
+                "over budget: {}",
+                over.iter()
+                    .map(|day| format!("day {:02}", day))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(day: u8, part: u8, millis: u64) -> Record {
+        Record {
+            time: 1702742400,
+            day,
+            part,
+            id: "input".to_string(),
+            build: Build::Release,
+            parse: Duration::from_micros(500),
+            solve: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn test_append_and_load() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-history-{}", std::process::id()));
+        let path = dir.join("timings.tsv");
+        let mut history = History::default();
+        let first = history.append(&path, &[record(1, 1, 3), record(1, 2, 4)]);
+        let second = history.append(&path, &[record(1, 1, 2)]);
+        let stored = std::fs::read_to_string(&path);
+        let loaded = History::load(&path);
+        std::fs::remove_dir_all(&dir)?;
+
+        first?;
+        second?;
+        assert_eq!(
+            [
+                HEADER,
+                "1702742400\t1\t1\tinput\trelease\t500000\t3000000",
+                "1702742400\t1\t2\tinput\trelease\t500000\t4000000",
+                "1702742400\t1\t1\tinput\trelease\t500000\t2000000\n",
+            ]
+            .join("\n"),
+            stored?
+        );
+        assert_eq!(history.records(), loaded?.records());
+        assert!(History::load(&path)?.records().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = History::parse("# header\n1\t1\t1\tinput\tfast\t1\t1").expect_err("unknown build");
+        assert_eq!(
+            "line 2: unknown build `fast`, expected debug or release",
+            format!("{:#}", e)
+        );
+    }
+
+    #[test]
+    fn test_regression() {
+        let history = History {
+            records: vec![record(5, 2, 20), record(5, 2, 10), record(5, 2, 30)],
+        };
+        assert_eq!(
+            Some(Duration::from_micros(10_500)),
+            history.best(&record(5, 2, 0))
+        );
+        let regression = history
+            .regression(&record(5, 2, 14), 20.0)
+            .expect("38% slower");
+        assert_eq!(Duration::from_micros(14_500), regression.total);
+        assert!((regression.percent - 38.095).abs() < 0.01);
+        assert_eq!(None, history.regression(&record(5, 2, 12), 20.0));
+        assert_eq!(None, history.regression(&record(5, 1, 1000), 20.0));
+        let debug = Record {
+            build: Build::Debug,
+            ..record(5, 2, 1000)
+        };
+        assert_eq!(None, history.regression(&debug, 20.0));
+        // tiny timings jitter by far more than 20%
+        let tiny = History {
+            records: vec![record(1, 1, 0)],
+        };
+        assert_eq!(None, tiny.regression(&record(1, 1, 1), 20.0));
+    }
+
+    #[test]
+    fn test_report() {
+        let history = History {
+            records: vec![
+                record(1, 1, 1),
+                record(1, 2, 2),
+                record(5, 1, 1),
+                record(5, 2, 90),
+                record(5, 2, 10),
+                record(7, 2, 300),
+            ],
+        };
+        let report = Report {
+            history: &history,
+            days: &[1, 5, 7, 8],
+            id: "input",
+            build: Build::Release,
+            budget: Duration::from_secs(1),
+        };
+        assert_eq!(vec![7], report.over());
+        assert_eq!(
+            "\
+day  part 1    part 2     total  cumulative  status
+ 01  1.50ms    2.50ms    4.00ms      4.00ms  ok
+ 05  1.50ms   10.50ms   12.00ms     16.00ms  ok
+ 07       -  300.50ms  300.50ms    316.50ms  OVER
+ 08       -         -         -    316.50ms  missing
+release build, 316.50ms of 1.00s (40.00ms per day), over budget: day 07",
+            report.to_string()
+        );
+    }
+}
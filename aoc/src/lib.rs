@@ -0,0 +1,12 @@
+pub mod days;
+pub mod fetch;
+pub mod history;
+pub mod parallel;
+pub mod profile;
+pub mod scaffold;
+pub mod select;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod table;
+pub mod verify;
+pub mod visualize;
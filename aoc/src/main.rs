@@ -0,0 +1,652 @@
+use anyhow::{bail, Context};
+use aoc::{
+    days,
+    fetch::{Fetched, Fetcher, Ureq, SESSION_ENV},
+    history::{self, Build, History, Record, Report, HISTORY_ENV},
+    parallel, profile, scaffold,
+    select::Selection,
+    verify::{self, Status, Table},
+    visualize::{self, Playback},
+};
+use aoc_core::{
+    answers::{self, ANSWERS_ENV},
+    examples,
+    input::{workspace_root, Inputs, DEFAULT_ID, INPUT_DIR_ENV},
+    Answers, Run, Solver,
+};
+use aoc_grid::image;
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use day_01::{calibration, dictionary::Dictionary};
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::{fmt::format::FmtSpan, EnvFilter};
+
+#[cfg(feature = "heap-profile")]
+#[global_allocator]
+static ALLOC: aoc_core::heap::Counting = aoc_core::heap::Counting;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    /// Directory with the puzzle inputs, stored as `day-XX/input.txt`
+    #[arg(long, global = true, env = INPUT_DIR_ENV)]
+    input_dir: Option<PathBuf>,
+    /// File the timings of `run` are appended to, `timings.tsv` in the workspace by default
+    #[arg(long, global = true, env = HISTORY_ENV)]
+    history: Option<PathBuf>,
+    /// Trace the solutions on stderr: `-v` times every phase, `-vv` adds debug events, `-vvv` everything.
+    /// `RUST_LOG` filters further, e.g. `RUST_LOG=day_12=trace`
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+    /// Format of the traces
+    #[arg(long, global = true, value_enum, default_value_t = LogFormat::Text)]
+    log_format: LogFormat,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum LogFormat {
+    Text,
+    /// One JSON object per line
+    Json,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one or more days
+    Run {
+        /// Days to run, e.g. `16`, `5..=9` or `1,3,5..7`
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        days: Option<Selection>,
+        /// Only run this part
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Run every known day
+        #[arg(long)]
+        all: bool,
+        /// Threads solving the days of `--all`, 1 by default and 0 for one per core. Parts
+        /// compete for the cores, so timings are only recorded with a single thread
+        #[arg(long, conflicts_with = "days")]
+        jobs: Option<usize>,
+        /// Input to use, `inputs/day-XX/<id>.txt`
+        #[arg(long, default_value = DEFAULT_ID)]
+        id: String,
+        /// Animate the days which have a visualisation (10, 11, 14 and 16) before solving them
+        #[arg(long)]
+        visualize: bool,
+        /// Milliseconds between two frames of the animation
+        #[arg(long, default_value_t = 40, requires = "visualize")]
+        delay: u64,
+        /// Only draw every nth frame of the animation
+        #[arg(long, default_value_t = 1, requires = "visualize")]
+        every: usize,
+        /// Advance the animation with Enter instead of a timer
+        #[arg(long, requires = "visualize")]
+        step: bool,
+        /// Warn if a part is more than this many percent slower than its best recorded run
+        #[arg(long, default_value_t = 20.0)]
+        tolerance: f64,
+        /// Don't append the timings to the history
+        #[arg(long)]
+        no_history: bool,
+    },
+    /// Season table of the newest recorded timings against the budget of all days
+    Report {
+        /// Milliseconds all days of the season may take together, split evenly between them
+        #[arg(long, default_value_t = 1000)]
+        budget: u64,
+        /// Input the timings were recorded with
+        #[arg(long, default_value = DEFAULT_ID)]
+        id: String,
+        /// Build the timings were recorded with, the build of the runner by default
+        #[arg(long, default_value_t = Build::current())]
+        build: Build,
+    },
+    /// Check all solutions against the known answers
+    Verify {
+        /// Days to check, all if not given
+        days: Option<Selection>,
+        /// Only check this part
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Registry of known answers, `answers.toml` in the workspace by default
+        #[arg(long, env = ANSWERS_ENV)]
+        answers: Option<PathBuf>,
+    },
+    /// Report the heap usage of parsing and solving, needs `--features heap-profile`
+    Profile {
+        /// Days to profile, all if not given
+        days: Option<Selection>,
+        /// Only profile this part
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input to use, `inputs/day-XX/<id>.txt`
+        #[arg(long, default_value = DEFAULT_ID)]
+        id: String,
+    },
+    /// Show how day 1 reads every line: the value and the first and last digit underlined
+    Audit {
+        /// Part whose rules to apply
+        #[arg(default_value_t = 2, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Number words of part 2: `english`, `german`, `french` or a file of `word = value`
+        /// lines. The puzzle's `one` to `nine` by default
+        #[arg(long)]
+        dictionary: Option<String>,
+        /// Only show the lines without a digit
+        #[arg(long)]
+        errors: bool,
+        /// Input to use, `inputs/day-XX/<id>.txt`
+        #[arg(long, default_value = DEFAULT_ID)]
+        id: String,
+    },
+    /// Save a picture of the grid of day 10, 11, 14 or 16 as PNG or SVG
+    Export {
+        day: u8,
+        /// Where to write the picture, the extension picks the format: `.png` or `.svg`
+        path: PathBuf,
+        /// Spin cycles for day 14
+        #[arg(long, default_value_t = 1)]
+        steps: usize,
+        /// Pixels per cell
+        #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u32).range(1..=64))]
+        scale: u32,
+        /// Input to use, `inputs/day-XX/<id>.txt`
+        #[arg(long, default_value = DEFAULT_ID)]
+        id: String,
+    },
+    /// Write a random but valid input, e.g. to stress test or benchmark a day
+    Generate {
+        day: u8,
+        /// Seed of the random number generator, the same seed gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Size of the input, e.g. lines or width. Defaults to about the size of a real input
+        #[arg(long)]
+        size: Option<usize>,
+        /// Store it as `inputs/day-XX/<id>.txt` instead of printing it
+        #[arg(long)]
+        save: Option<String>,
+    },
+    /// Download missing inputs, stored ones are never downloaded again
+    Fetch {
+        /// Days to fetch, all known days if not given
+        days: Option<Selection>,
+        /// Value of the `session` cookie of adventofcode.com
+        #[arg(long, env = SESSION_ENV, hide_env_values = true)]
+        session: Option<String>,
+    },
+    /// Extract the examples of a saved puzzle page into `day-XX/examples`
+    Examples {
+        day: u8,
+        /// The puzzle page saved from the browser
+        html: PathBuf,
+        /// Replace examples which were extracted before
+        #[arg(long)]
+        force: bool,
+    },
+    /// Create a new day crate from `daily-template` and register it with the runner
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+/// Where and how `run` keeps the timings
+struct Recording<'a> {
+    path: &'a Path,
+    tolerance: f64,
+}
+
+fn run(
+    inputs: &Inputs,
+    days: Selection,
+    part: Option<u8>,
+    id: &str,
+    playback: Option<Playback>,
+    recording: Option<Recording>,
+    jobs: Option<usize>,
+) -> bool {
+    let mut success = true;
+    if let Some(playback) = playback {
+        success = animate(inputs, &days, id, playback);
+    }
+    // a broken history shouldn't stop the solvers, it is only not written to
+    let mut history = recording.as_ref().and_then(|recording| {
+        History::load(recording.path)
+            .inspect_err(|e| eprintln!("not recording timings: {:#}", e))
+            .ok()
+    });
+    let mut records = Vec::new();
+    let solvers: Vec<Solver> = days::solvers()
+        .into_iter()
+        .filter(|solver| days.contains(solver.day))
+        .filter(|solver| part.is_none_or(|part| part == solver.part))
+        .collect();
+    // in parallel the results are printed at the end, one after another they are printed as they come
+    let results: Box<dyn Iterator<Item = anyhow::Result<Run>>> = match jobs {
+        Some(jobs) => match parallel::solve_all(&solvers, inputs, id, jobs) {
+            Ok(results) => Box::new(results.into_iter()),
+            Err(e) => {
+                eprintln!("starting the thread pool: {:#}", e);
+                return false;
+            }
+        },
+        None => Box::new(
+            solvers
+                .iter()
+                .map(|solver| parallel::solve(solver, inputs, id)),
+        ),
+    };
+    for (solver, result) in solvers.iter().zip(results) {
+        match result {
+            Ok(run) => {
+                println!(
+                    "{:?}: {:<20} (parse {:>10.2?}, solve {:>10.2?})",
+                    solver, run.answer, run.parse, run.solve
+                );
+                let record = Record::now(solver.day, solver.part, id, run.parse, run.solve);
+                let regression =
+                    history
+                        .as_ref()
+                        .zip(recording.as_ref())
+                        .and_then(|(history, recording)| {
+                            history.regression(&record, recording.tolerance)
+                        });
+                if let Some(regression) = regression {
+                    eprintln!("{:?}: warning: {}", solver, regression);
+                }
+                records.push(record);
+            }
+            Err(e) => {
+                success = false;
+                eprintln!("{:?}: {:#}", solver, e)
+            }
+        }
+    }
+    if let Some((history, recording)) = history.as_mut().zip(recording) {
+        if let Err(e) = history.append(recording.path, &records) {
+            eprintln!("not recording timings: {:#}", e);
+        }
+    }
+    success
+}
+
+fn report(history: &Path, budget: Duration, id: &str, build: Build) -> anyhow::Result<()> {
+    let history = History::load(history)?;
+    let mut days: Vec<u8> = days::solvers().iter().map(|solver| solver.day).collect();
+    days.dedup();
+    let report = Report {
+        history: &history,
+        days: &days,
+        id,
+        build,
+        budget,
+    };
+    println!("{}", report);
+    Ok(())
+}
+
+fn animate(inputs: &Inputs, days: &Selection, id: &str, playback: Playback) -> bool {
+    let mut selected: Vec<u8> = days::solvers()
+        .iter()
+        .map(|solver| solver.day)
+        .filter(|day| days.contains(*day))
+        .collect();
+    selected.dedup();
+    let mut success = true;
+    for day in selected {
+        let Some(visualizer) = days::visualizer(day) else {
+            eprintln!("day {:02}: no visualisation", day);
+            continue;
+        };
+        let result = inputs.load(day, id).and_then(|input| {
+            let frames = visualizer.frames(&input)?;
+            let drawn = visualize::play(
+                frames,
+                playback,
+                &mut std::io::stdout().lock(),
+                &mut std::io::stdin().lock(),
+            )?;
+            Ok(drawn)
+        });
+        if let Err(e) = result {
+            success = false;
+            eprintln!("day {:02}: {:#}", day, e);
+        }
+    }
+    success
+}
+
+fn verify(inputs: &Inputs, answers: &Answers, days: Selection, part: Option<u8>) -> bool {
+    let solvers: Vec<_> = days::solvers()
+        .into_iter()
+        .filter(|solver| days.contains(solver.day))
+        .filter(|solver| part.is_none_or(|part| part == solver.part))
+        .collect();
+    let checks = verify::verify(&solvers, inputs, answers);
+    println!("{}", Table(&checks));
+    checks.iter().all(|check| check.status != Status::Fail)
+}
+
+fn profile(inputs: &Inputs, days: Selection, part: Option<u8>, id: &str) -> bool {
+    if cfg!(not(feature = "heap-profile")) {
+        eprintln!("allocations aren't counted, build with `--features heap-profile`");
+        return false;
+    }
+    let solvers: Vec<_> = days::solvers()
+        .into_iter()
+        .filter(|solver| days.contains(solver.day))
+        .filter(|solver| part.is_none_or(|part| part == solver.part))
+        .collect();
+    let measurements = profile::profile(&solvers, inputs, id);
+    println!("{}", profile::Table(&measurements));
+    measurements
+        .iter()
+        .all(|measurement| measurement.result.is_ok())
+}
+
+fn audit(
+    inputs: &Inputs,
+    part: u8,
+    dictionary: Option<&str>,
+    errors: bool,
+    id: &str,
+) -> anyhow::Result<()> {
+    let dictionary = match (part, dictionary) {
+        (1, None) => Dictionary::digits(),
+        (1, Some(_)) => bail!("part 1 reads no words, --dictionary needs part 2"),
+        (_, None) => Dictionary::english().only_digits(),
+        (_, Some("english")) => Dictionary::english(),
+        (_, Some("german")) => Dictionary::german(),
+        (_, Some("french")) => Dictionary::french(),
+        (_, Some(path)) => Dictionary::load(Path::new(path))?,
+    };
+    let input = inputs.load(1, id)?;
+    let lines: Vec<_> = calibration::calibrate(&dictionary, input.as_bytes()).collect();
+    println!(
+        "{}",
+        calibration::Annotated {
+            input: input.as_bytes(),
+            lines: &lines,
+            errors_only: errors,
+        }
+    );
+    let failed = lines.iter().filter(|line| line.is_err()).count();
+    if failed > 0 {
+        bail!("not every line has a digit");
+    }
+    Ok(())
+}
+
+fn export(
+    inputs: &Inputs,
+    day: u8,
+    path: &Path,
+    steps: usize,
+    scale: u32,
+    id: &str,
+) -> anyhow::Result<()> {
+    let Some(visualizer) = days::visualizer(day) else {
+        bail!("day {} has no picture", day);
+    };
+    if image::Format::from_path(path).is_none() {
+        bail!("{} is neither .png nor .svg", path.display());
+    }
+    let input = inputs.load(day, id)?;
+    let picture = visualizer.picture(&input, steps)?;
+    image::save(&picture, path, scale).with_context(|| format!("writing {}", path.display()))?;
+    eprintln!("wrote {}", path.display());
+    Ok(())
+}
+
+fn generate(
+    inputs: &Inputs,
+    day: u8,
+    seed: u64,
+    size: Option<usize>,
+    save: Option<&str>,
+) -> anyhow::Result<()> {
+    let Some(generator) = days::generator(day) else {
+        bail!("no generator for day {}", day);
+    };
+    let input = generator.generate(seed, size.unwrap_or(generator.default_size));
+    match save {
+        Some(id) => {
+            let path = inputs.path(day, id);
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)
+                    .with_context(|| format!("creating {}", dir.display()))?;
+            }
+            std::fs::write(&path, input).with_context(|| format!("writing {}", path.display()))?;
+            eprintln!("wrote {}", path.display());
+        }
+        None => print!("{}", input),
+    }
+    Ok(())
+}
+
+fn fetch(inputs: &Inputs, days: Option<Selection>, session: Option<String>) -> bool {
+    let days: Vec<u8> = match days {
+        Some(days) => (1..=25).filter(|&day| days.contains(day)).collect(),
+        None => {
+            let mut days: Vec<u8> = days::solvers().iter().map(|solver| solver.day).collect();
+            days.dedup();
+            days
+        }
+    };
+    let fetcher = Fetcher::new(Ureq::default(), inputs.clone(), session);
+    let mut success = true;
+    for day in days {
+        match fetcher.fetch(day) {
+            Ok(Fetched::Cached(path)) => eprintln!("day {:02}: cached at {}", day, path.display()),
+            Ok(Fetched::Downloaded(path)) => {
+                eprintln!("day {:02}: downloaded to {}", day, path.display())
+            }
+            Err(e) => {
+                success = false;
+                eprintln!("day {:02}: {:#}", day, e)
+            }
+        }
+    }
+    success
+}
+
+fn extract_examples(day: u8, html: &Path, force: bool) -> anyhow::Result<()> {
+    let crate_dir = workspace_root().join(format!("day-{:02}", day));
+    if !crate_dir.exists() {
+        bail!(
+            "there is no {}, create it with `aoc new {}`",
+            crate_dir.display(),
+            day
+        );
+    }
+    let dir = crate_dir.join("examples");
+    if dir.exists() {
+        if !force {
+            bail!(
+                "{} already exists, pass --force to replace it",
+                dir.display()
+            );
+        }
+        std::fs::remove_dir_all(&dir).with_context(|| format!("removing {}", dir.display()))?;
+    }
+    let page =
+        std::fs::read_to_string(html).with_context(|| format!("reading {}", html.display()))?;
+    let parts = examples::extract(&page);
+    if parts.iter().all(|part| part.examples.is_empty()) {
+        bail!("no `<pre><code>` examples in {}", html.display());
+    }
+    for path in examples::write(&dir, &parts)? {
+        eprintln!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn new_day(inputs: &Inputs, day: u8) -> anyhow::Result<()> {
+    let root = workspace_root();
+    let created = scaffold::new_day(&root.join("daily-template"), &root, inputs, day)?;
+    for path in created {
+        eprintln!("created {}", path.display());
+    }
+    eprintln!(
+        "registered day {} in aoc/src/days.rs and aoc/Cargo.toml",
+        day
+    );
+    Ok(())
+}
+
+/// Installs the subscriber for the spans and events the solutions emit
+fn init_tracing(verbose: u8, format: LogFormat) {
+    let level = match verbose {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    };
+    let filter = EnvFilter::builder()
+        .with_default_directive(level.into())
+        .from_env_lossy();
+    let subscriber = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(std::io::stderr);
+    match format {
+        LogFormat::Text => subscriber.init(),
+        LogFormat::Json => subscriber.json().init(),
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_tracing(cli.verbose, cli.log_format);
+    let inputs = cli.input_dir.map_or_else(Inputs::from_env, Inputs::new);
+    let history = cli.history.unwrap_or_else(history::path_from_env);
+    let success = match cli.command {
+        Command::Run {
+            days,
+            part,
+            all,
+            jobs,
+            id,
+            visualize,
+            delay,
+            every,
+            step,
+            tolerance,
+            no_history,
+        } => {
+            let days = if all {
+                Selection::all()
+            } else {
+                days.expect("clap requires days without --all")
+            };
+            let playback = visualize.then_some(Playback {
+                delay: Duration::from_millis(delay),
+                every,
+                step,
+            });
+            let jobs = all.then(|| jobs.unwrap_or(1));
+            // parts competing for the cores aren't comparable with undisturbed runs
+            let contended = jobs.is_some_and(|jobs| jobs != 1);
+            if contended && !no_history {
+                eprintln!(
+                    "not recording timings of parallel runs, leave out --jobs to record them"
+                );
+            }
+            let recording = (!no_history && !contended).then_some(Recording {
+                path: &history,
+                tolerance,
+            });
+            run(&inputs, days, part, &id, playback, recording, jobs)
+        }
+        Command::Report { budget, id, build } => {
+            match report(&history, Duration::from_millis(budget), &id, build) {
+                Ok(()) => true,
+                Err(e) => {
+                    eprintln!("{:#}", e);
+                    false
+                }
+            }
+        }
+        Command::Verify {
+            days,
+            part,
+            answers,
+        } => {
+            let path = answers.unwrap_or_else(answers::path_from_env);
+            match Answers::load(&path) {
+                Ok(answers) => verify(&inputs, &answers, days.unwrap_or_else(Selection::all), part),
+                Err(e) => {
+                    eprintln!("{:#}", e);
+                    false
+                }
+            }
+        }
+        Command::Profile { days, part, id } => {
+            profile(&inputs, days.unwrap_or_else(Selection::all), part, &id)
+        }
+        Command::Audit {
+            part,
+            dictionary,
+            errors,
+            id,
+        } => match audit(&inputs, part, dictionary.as_deref(), errors, &id) {
+            Ok(()) => true,
+            Err(e) => {
+                eprintln!("{:#}", e);
+                false
+            }
+        },
+        Command::Export {
+            day,
+            path,
+            steps,
+            scale,
+            id,
+        } => match export(&inputs, day, &path, steps, scale, &id) {
+            Ok(()) => true,
+            Err(e) => {
+                eprintln!("{:#}", e);
+                false
+            }
+        },
+        Command::Generate {
+            day,
+            seed,
+            size,
+            save,
+        } => match generate(&inputs, day, seed, size, save.as_deref()) {
+            Ok(()) => true,
+            Err(e) => {
+                eprintln!("{:#}", e);
+                false
+            }
+        },
+        Command::Fetch { days, session } => fetch(&inputs, days, session),
+        Command::Examples { day, html, force } => match extract_examples(day, &html, force) {
+            Ok(()) => true,
+            Err(e) => {
+                eprintln!("{:#}", e);
+                false
+            }
+        },
+        Command::New { day } => match new_day(&inputs, day) {
+            Ok(()) => true,
+            Err(e) => {
+                eprintln!("{:#}", e);
+                false
+            }
+        },
+    };
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,125 @@
+//! `aoc run --all`: every part solved concurrently on a thread pool
+//!
+//! Results come back in the order of the solvers, however the tasks were
+//! scheduled. Each part is timed inside its own task, so waiting for a thread
+//! isn't counted, and a panicking part only fails itself.
+use aoc_core::{input::Inputs, Run, Solver};
+use rayon::prelude::*;
+
+/// Loads the input of `solver` and runs it, a panic becomes an error
+pub fn solve(solver: &Solver, inputs: &Inputs, id: &str) -> anyhow::Result<Run> {
+    let input = inputs.load(solver.day, id)?;
+    solver.run_isolated(&input)
+}
+
+/// Solves every solver on a pool of `jobs` threads, `0` is one per core
+///
+/// Parts which parallelise themselves, like day 16 part 2, share the same pool.
+pub fn solve_all(
+    solvers: &[Solver],
+    inputs: &Inputs,
+    id: &str,
+    jobs: usize,
+) -> anyhow::Result<Vec<anyhow::Result<Run>>> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .thread_name(|idx| format!("aoc-{}", idx))
+        .build()?;
+    Ok(pool.install(|| {
+        solvers
+            .par_iter()
+            .map(|solver| solve(solver, inputs, id))
+            .collect()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{Panicked, Solution};
+    use std::time::Duration;
+
+    /// Sleeps a millisecond per line, so the first solver finishes last
+    struct Sleepy;
+
+    impl Solution for Sleepy {
+        const DAY: u8 = 1;
+        const PART: u8 = 1;
+        type Input<'a> = usize;
+        type Answer = usize;
+
+        fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+            Ok(input.lines().count())
+        }
+
+        fn solve(input: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+            std::thread::sleep(Duration::from_millis(input as u64));
+            Ok(input)
+        }
+    }
+
+    struct Unfinished;
+
+    impl Solution for Unfinished {
+        const DAY: u8 = 2;
+        const PART: u8 = 1;
+        type Input<'a> = &'a str;
+        type Answer = usize;
+
+        fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+            Ok(input)
+        }
+
+        fn solve(_input: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+            panic!("unfinished")
+        }
+    }
+
+    struct Quick;
+
+    impl Solution for Quick {
+        const DAY: u8 = 2;
+        const PART: u8 = 2;
+        type Input<'a> = &'a str;
+        type Answer = usize;
+
+        fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+            Ok(input)
+        }
+
+        fn solve(input: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+            Ok(input.len())
+        }
+    }
+
+    #[test]
+    fn test_solve_all() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-parallel-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("day-01"))?;
+        std::fs::create_dir_all(dir.join("day-02"))?;
+        std::fs::write(dir.join("day-01/input.txt"), "x\n".repeat(50))?;
+        std::fs::write(dir.join("day-02/input.txt"), "abc")?;
+
+        let solvers = [
+            Solver::new::<Sleepy>(),
+            Solver::new::<Unfinished>(),
+            Solver::new::<Quick>(),
+        ];
+        let results = solve_all(&solvers, &Inputs::new(&dir), "input", 3);
+        let missing = solve_all(&solvers[..1], &Inputs::new(&dir), "other", 0);
+        std::fs::remove_dir_all(&dir)?;
+
+        let results = results?;
+        assert_eq!(3, results.len());
+        let sleepy = results[0].as_ref().expect("solved");
+        assert_eq!("50", sleepy.answer);
+        assert!(sleepy.solve >= Duration::from_millis(50));
+        assert!(results[1]
+            .as_ref()
+            .expect_err("unfinished")
+            .is::<Panicked>());
+        assert_eq!("3", results[2].as_ref().expect("solved").answer);
+        assert!(missing?[0].is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+//! `aoc profile`: heap usage of every part, split into parsing and solving
+use crate::table;
+use aoc_core::{heap::HeapStats, input::Inputs, Profile, Solver};
+use std::fmt;
+
+/// Heap usage of one solver on one input, or why there is none
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    pub day: u8,
+    pub part: u8,
+    pub result: Result<Profile, String>,
+}
+
+/// Profiles the solvers one after another, they have the allocator to themselves
+///
+/// A panicking solver only fails its own row.
+pub fn profile(solvers: &[Solver], inputs: &Inputs, id: &str) -> Vec<Measurement> {
+    solvers
+        .iter()
+        .map(|solver| Measurement {
+            day: solver.day,
+            part: solver.part,
+            result: inputs
+                .load(solver.day, id)
+                .and_then(|input| solver.profile_isolated(&input))
+                .map_err(|e| format!("{:#}", e)),
+        })
+        .collect()
+}
+
+/// Measurements rendered as a table, one line per phase
+///
+/// Sizes are exact byte counts so tables of two commits can be diffed.
+pub struct Table<'a>(pub &'a [Measurement]);
+
+impl fmt::Display for Table<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stats = |phase: &str, stats: HeapStats| {
+            [
+                phase.to_string(),
+                stats.peak.to_string(),
+                stats.total.to_string(),
+                stats.allocations.to_string(),
+                String::new(),
+            ]
+        };
+        let rows: Vec<[String; 7]> = self
+            .0
+            .iter()
+            .flat_map(|measurement| {
+                let phases = match &measurement.result {
+                    Ok(profile) => {
+                        vec![stats("parse", profile.parse), stats("solve", profile.solve)]
+                    }
+                    Err(e) => vec![[
+                        String::new(),
+                        String::new(),
+                        String::new(),
+                        String::new(),
+                        e.clone(),
+                    ]],
+                };
+                phases.into_iter().map(|[phase, peak, total, count, note]| {
+                    [
+                        measurement.day.to_string(),
+                        measurement.part.to_string(),
+                        phase,
+                        peak,
+                        total,
+                        count,
+                        note,
+                    ]
+                })
+            })
+            .collect();
+        table::render(
+            f,
+            [
+                "day",
+                "part",
+                "phase",
+                "peak",
+                "total",
+                "allocations",
+                "note",
+            ],
+            &rows,
+            &[0, 1, 3, 4, 5],
+        )?;
+        let profiles: Vec<&Profile> = self
+            .0
+            .iter()
+            .filter_map(|m| m.result.as_ref().ok())
+            .collect();
+        let phases = || {
+            profiles
+                .iter()
+                .flat_map(|profile| [profile.parse, profile.solve])
+        };
+        write!(
+            f,
+            "{} profiled, {} failed, highest peak {} bytes, {} allocations",
+            profiles.len(),
+            self.0.len() - profiles.len(),
+            phases().map(|stats| stats.peak).max().unwrap_or_default(),
+            phases().map(|stats| stats.allocations).sum::<usize>()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table() {
+        let measurements = [
+            Measurement {
+                day: 13,
+                part: 1,
+                result: Ok(Profile {
+                    answer: "405".to_string(),
+                    parse: HeapStats {
+                        peak: 2048,
+                        total: 4096,
+                        allocations: 12,
+                    },
+                    solve: HeapStats {
+                        peak: 512,
+                        total: 1024,
+                        allocations: 3,
+                    },
+                }),
+            },
+            Measurement {
+                day: 14,
+                part: 2,
+                result: Err("no input".to_string()),
+            },
+        ];
+        assert_eq!(
+            "\
+day  part  phase  peak  total  allocations  note
+ 13     1  parse  2048   4096           12
+ 13     1  solve   512   1024            3
+ 14     2                                   no input
+1 profiled, 1 failed, highest peak 2048 bytes, 15 allocations",
+            Table(&measurements).to_string()
+        );
+    }
+}
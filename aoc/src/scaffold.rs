@@ -0,0 +1,250 @@
+//! `aoc new`: creates a day crate from `daily-template`
+use anyhow::{bail, ensure, Context};
+use aoc_core::input::{Inputs, DEFAULT_ID};
+use std::path::{Path, PathBuf};
+
+/// Template files which aren't copied into a day
+const SKIPPED: [&str; 1] = ["target"];
+
+/// Line in `aoc/Cargo.toml` the day dependencies follow
+const DAYS_MARKER: &str = "# days";
+
+/// Creates `day-XX` in `root` from `template` and registers it with the runner
+///
+/// Fills in `{{day}}`, `{{crate_name}}` and `{{project-name}}`, adds the day to
+/// `aoc/src/days.rs` and `aoc/Cargo.toml` and creates an empty input. Refuses
+/// to touch a day which already exists. Returns the created files.
+pub fn new_day(
+    template: &Path,
+    root: &Path,
+    inputs: &Inputs,
+    day: u8,
+) -> anyhow::Result<Vec<PathBuf>> {
+    ensure!((1..=25).contains(&day), "there is no day {}", day);
+    let name = format!("day-{:02}", day);
+    let target = root.join(&name);
+    ensure!(!target.exists(), "{} already exists", target.display());
+
+    // check both registrations before writing anything
+    let days_path = root.join("aoc/src/days.rs");
+    let manifest_path = root.join("aoc/Cargo.toml");
+    let days = register_day(&read(&days_path)?, day)?;
+    let manifest = register_dependency(&read(&manifest_path)?, day)?;
+
+    let mut created = Vec::new();
+    copy_template(template, &target, day, &mut created)?;
+    write(&days_path, &days)?;
+    write(&manifest_path, &manifest)?;
+
+    let input = inputs.path(day, DEFAULT_ID);
+    if !input.exists() {
+        if let Some(dir) = input.parent() {
+            std::fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+        }
+        write(&input, "")?;
+        created.push(input);
+    }
+    Ok(created)
+}
+
+fn read(path: &Path) -> anyhow::Result<String> {
+    std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> anyhow::Result<()> {
+    std::fs::write(path, contents).with_context(|| format!("writing {}", path.display()))
+}
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{day}}", &day.to_string())
+        .replace("{{crate_name}}", &format!("day_{:02}", day))
+        .replace("{{project-name}}", &format!("day-{:02}", day))
+}
+
+fn copy_template(
+    template: &Path,
+    target: &Path,
+    day: u8,
+    created: &mut Vec<PathBuf>,
+) -> anyhow::Result<()> {
+    std::fs::create_dir_all(target).with_context(|| format!("creating {}", target.display()))?;
+    let entries =
+        std::fs::read_dir(template).with_context(|| format!("reading {}", template.display()))?;
+    for entry in entries {
+        let entry = entry?;
+        if SKIPPED.iter().any(|skipped| entry.file_name() == *skipped) {
+            continue;
+        }
+        let path = target.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_template(&entry.path(), &path, day, created)?;
+        } else {
+            write(&path, &render(&read(&entry.path())?, day))?;
+            created.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Adds `day_XX` to the `days![...]` list, keeping it sorted and wrapped like rustfmt does
+fn register_day(source: &str, day: u8) -> anyhow::Result<String> {
+    const OPEN: &str = "days![\n";
+    const CLOSE: &str = "];\n";
+    let start = source.find(OPEN).context("no `days![` list")? + OPEN.len();
+    let end = start
+        + source[start..]
+            .find(CLOSE)
+            .context("unterminated `days![` list")?;
+
+    let ident = format!("day_{:02}", day);
+    let mut days: Vec<&str> = source[start..end]
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|day| !day.is_empty())
+        .collect();
+    if days.contains(&ident.as_str()) {
+        bail!("day {} is already registered in the runner", day);
+    }
+    days.push(&ident);
+    days.sort_unstable();
+
+    let mut list = String::new();
+    let mut line = String::new();
+    for day in days {
+        if !line.is_empty() && 4 + line.len() + 1 + day.len() + 1 > 100 {
+            list.push_str(&format!("    {}\n", line));
+            line.clear();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(day);
+        line.push(',');
+    }
+    list.push_str(&format!("    {}\n", line));
+    Ok(format!("{}{}{}", &source[..start], list, &source[end..]))
+}
+
+/// Adds the path dependency on `day-XX` to the days of the runner manifest
+fn register_dependency(manifest: &str, day: u8) -> anyhow::Result<String> {
+    let name = format!("day-{:02}", day);
+    let dependency = format!("{name} = {{ path = \"../{name}\" }}");
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    let marker = lines
+        .iter()
+        .position(|line| *line == DAYS_MARKER)
+        .with_context(|| format!("no `{}` section", DAYS_MARKER))?;
+    let days = lines[marker + 1..]
+        .iter()
+        .take_while(|line| line.starts_with("day-"))
+        .count();
+    let block = &lines[marker + 1..marker + 1 + days];
+    if block
+        .iter()
+        .any(|line| line.starts_with(&format!("{name} ")))
+    {
+        bail!("{} is already a dependency of the runner", name);
+    }
+    let position = marker + 1 + block.partition_point(|line| *line < dependency.as_str());
+    lines.insert(position, &dependency);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::{Fetched, Fetcher, Http};
+
+    const DAYS: &str = "days![\n    day_01, day_03,\n];\n";
+    const MANIFEST: &str = r#"[dependencies]
+anyhow = "1"
+
+# days
+day-01 = { path = "../day-01" }
+day-03 = { path = "../day-03" }
+
+[dev-dependencies]
+"#;
+
+    #[test]
+    fn test_register_day() -> anyhow::Result<()> {
+        let days = register_day(DAYS, 2)?;
+        assert_eq!("days![\n    day_01, day_02, day_03,\n];\n", days);
+        assert!(register_day(&days, 2).is_err());
+
+        let days = (4..=25).try_fold(days, |days, day| register_day(&days, day))?;
+        assert!(days.lines().all(|line| line.len() <= 100));
+        assert!(days.contains("day_12,\n    day_13,"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_dependency() -> anyhow::Result<()> {
+        let manifest = register_dependency(MANIFEST, 2)?;
+        assert!(manifest.contains(
+            "# days\nday-01 = { path = \"../day-01\" }\nday-02 = { path = \"../day-02\" }\nday-03"
+        ));
+        assert!(register_dependency(&manifest, 2).is_err());
+        assert!(register_dependency(MANIFEST, 3).is_err());
+        assert!(register_dependency("[dependencies]\n", 2).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_day() -> anyhow::Result<()> {
+        let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("aoc/src"))?;
+        std::fs::write(root.join("aoc/src/days.rs"), DAYS)?;
+        std::fs::write(root.join("aoc/Cargo.toml"), MANIFEST)?;
+        let template = Path::new(env!("CARGO_MANIFEST_DIR")).join("../daily-template");
+        let inputs = Inputs::new(root.join("inputs"));
+
+        let created = new_day(&template, &root, &inputs, 17);
+        let again = new_day(&template, &root, &inputs, 17);
+        let part1 = std::fs::read_to_string(root.join("day-17/src/bin/part1.rs"));
+        let manifest = std::fs::read_to_string(root.join("day-17/Cargo.toml"));
+        let input = std::fs::read_to_string(inputs.path(17, DEFAULT_ID));
+        std::fs::remove_dir_all(&root)?;
+
+        assert!(created?.len() > 5);
+        assert!(again.is_err());
+        assert!(part1?.contains("use day_17::part1::Part1;"));
+        assert!(manifest?.contains("name = \"day-17\""));
+        assert_eq!("", input?);
+        Ok(())
+    }
+
+    /// Answers every request with the same input
+    struct Fixed(&'static str);
+
+    impl Http for Fixed {
+        fn get(&self, _url: &str, _session: &str) -> anyhow::Result<String> {
+            Ok(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn test_new_day_then_fetch() -> anyhow::Result<()> {
+        let root = std::env::temp_dir().join(format!("aoc-new-fetch-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("aoc/src"))?;
+        std::fs::write(root.join("aoc/src/days.rs"), DAYS)?;
+        std::fs::write(root.join("aoc/Cargo.toml"), MANIFEST)?;
+        let template = Path::new(env!("CARGO_MANIFEST_DIR")).join("../daily-template");
+        let inputs = Inputs::new(root.join("inputs"));
+        let fetcher = Fetcher::new(Fixed("1abc2\n"), inputs.clone(), Some("token".to_string()))
+            .with_interval(std::time::Duration::ZERO);
+
+        let created = new_day(&template, &root, &inputs, 17);
+        let fetched = fetcher.fetch(17);
+        let again = fetcher.fetch(17);
+        let input = std::fs::read_to_string(inputs.path(17, DEFAULT_ID));
+        std::fs::remove_dir_all(&root)?;
+
+        created?;
+        let path = inputs.path(17, DEFAULT_ID);
+        assert_eq!(Fetched::Downloaded(path.clone()), fetched?);
+        assert_eq!(Fetched::Cached(path), again?);
+        assert_eq!("1abc2\n", input?);
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, space0},
+    combinator::{all_consuming, map, opt},
+    multi::separated_list1,
+    sequence::{delimited, pair, preceded},
+    IResult,
+};
+use std::{ops::RangeInclusive, str::FromStr};
+
+/// A set of days given on the command line
+///
+/// Accepts single days (`16`), ranges (`5..9`, `5..=9`)
+/// and comma separated lists of both (`1,3,5..=7`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection(Vec<RangeInclusive<u8>>);
+
+impl Selection {
+    pub fn all() -> Self {
+        Self(vec![1..=25])
+    }
+
+    pub fn contains(&self, day: u8) -> bool {
+        self.0.iter().any(|range| range.contains(&day))
+    }
+}
+
+fn range(input: &str) -> IResult<&str, RangeInclusive<u8>> {
+    map(
+        pair(
+            complete::u8,
+            opt(pair(alt((tag("..="), tag(".."))), complete::u8)),
+        ),
+        |(start, end)| match end {
+            None => start..=start,
+            Some(("..=", end)) => start..=end,
+            // `5..5` is empty, just like in rust
+            Some((_, end)) => start..=end.saturating_sub(1),
+        },
+    )(input)
+}
+
+fn selection(input: &str) -> IResult<&str, Selection> {
+    map(
+        separated_list1(delimited(space0, tag(","), space0), range),
+        Selection,
+    )(input)
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        all_consuming(preceded(space0, selection))(s.trim_end())
+            .map(|(_, selection)| selection)
+            .map_err(|_| format!("`{s}` is not a day, a range like `5..=9` or a list of those"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("16", vec![16])]
+    #[case("5..=9", vec![5, 6, 7, 8, 9])]
+    #[case("5..9", vec![5, 6, 7, 8])]
+    #[case("5..5", vec![])]
+    #[case("1, 3,5..=6", vec![1, 3, 5, 6])]
+    fn test_selection(#[case] input: &str, #[case] expected: Vec<u8>) {
+        let selection: Selection = input.parse().unwrap();
+        assert_eq!(
+            (1..=25)
+                .filter(|day| selection.contains(*day))
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("x")]
+    #[case("5..")]
+    #[case("1,,2")]
+    fn test_invalid_selection(#[case] input: &str) {
+        assert!(input.parse::<Selection>().is_err());
+    }
+}
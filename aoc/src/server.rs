@@ -0,0 +1,430 @@
+//! The solvers as a local HTTP/JSON service, served by the `server` binary
+//!
+//! - `GET /days` lists the known days and their parts
+//! - `POST /day/{n}/part/{p}` solves the request body and returns the answer with
+//!   timings, or the error with the phase it happened in and where parsing failed
+//!
+//! Requests are handled one after another, so timings aren't skewed by each other.
+//! Each solve runs on a worker thread and gets a deadline, after which the request
+//! is answered with 504. A thread can't be stopped, so the worker keeps running.
+use aoc_core::{Panicked, ParseError, Phase, Solver};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::{
+    io::Read,
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+use tiny_http::{Header, Method, Response, Server};
+
+/// Largest accepted input, real ones are a few KiB
+pub const MAX_INPUT: usize = 16 << 20;
+/// Time a solve gets by default, the slowest days take a few seconds
+pub const DEADLINE: Duration = Duration::from_secs(10);
+
+/// Entry of `GET /days`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Day {
+    pub day: u8,
+    pub parts: Vec<u8>,
+}
+
+/// Body of a solved `POST /day/{n}/part/{p}`
+#[derive(Debug, Clone, Serialize)]
+struct Solved {
+    day: u8,
+    part: u8,
+    answer: String,
+    timing: Timing,
+}
+
+/// Durations in microseconds
+#[derive(Debug, Clone, Serialize)]
+struct Timing {
+    parse_us: u128,
+    solve_us: u128,
+    total_us: u128,
+}
+
+/// Body of a failed `POST /day/{n}/part/{p}`
+#[derive(Debug, Clone, Serialize)]
+struct Failed {
+    day: u8,
+    part: u8,
+    error: String,
+    /// `parse` or `solve`, missing if the solver panicked
+    phase: Option<&'static str>,
+    /// Where parsing failed, if the solution knows
+    location: Option<Location>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct Location {
+    /// 1-based
+    line: usize,
+    /// 1-based, counted in chars
+    column: usize,
+    snippet: String,
+}
+
+/// Status and JSON body of a response
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reply {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl Reply {
+    fn ok(body: impl Serialize) -> Self {
+        Self::new(200, body)
+    }
+
+    fn new(status: u16, body: impl Serialize) -> Self {
+        Self {
+            status,
+            body: serde_json::to_value(body).expect("replies serialize to JSON"),
+        }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Self::new(status, json!({ "error": message.into() }))
+    }
+}
+
+/// Known days, the parts of each ordered like `solvers`
+pub fn days(solvers: &[Solver]) -> Vec<Day> {
+    let mut days: Vec<Day> = Vec::new();
+    for solver in solvers {
+        match days.last_mut() {
+            Some(day) if day.day == solver.day => day.parts.push(solver.part),
+            _ => days.push(Day {
+                day: solver.day,
+                parts: vec![solver.part],
+            }),
+        }
+    }
+    days
+}
+
+/// Answers one request, `url` may carry a query which is ignored
+pub fn handle(
+    solvers: &[Solver],
+    deadline: Duration,
+    method: &Method,
+    url: &str,
+    body: &[u8],
+) -> Reply {
+    let path = url.split('?').next().unwrap_or_default();
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match (method, segments.as_slice()) {
+        (Method::Get, ["days"]) => Reply::ok(days(solvers)),
+        (Method::Post, ["day", day, "part", part]) => {
+            let solver = day
+                .parse::<u8>()
+                .ok()
+                .zip(part.parse::<u8>().ok())
+                .and_then(|(day, part)| {
+                    solvers
+                        .iter()
+                        .find(|solver| solver.day == day && solver.part == part)
+                });
+            let Some(solver) = solver else {
+                return Reply::error(404, format!("no solver for day {} part {}", day, part));
+            };
+            match std::str::from_utf8(body) {
+                Ok(input) => solve(solver, input, deadline),
+                Err(e) => Reply::error(400, format!("the input isn't UTF-8: {}", e)),
+            }
+        }
+        (_, ["days"] | ["day", _, "part", _]) => {
+            Reply::error(405, format!("{} isn't allowed on {}", method, path))
+        }
+        _ => Reply::error(404, format!("no such resource: {}", path)),
+    }
+}
+
+fn solve(solver: &Solver, input: &str, deadline: Duration) -> Reply {
+    let failed = |error: String, phase, location| {
+        Reply::new(
+            422,
+            Failed {
+                day: solver.day,
+                part: solver.part,
+                error,
+                phase,
+                location,
+            },
+        )
+    };
+    let (sender, receiver) = mpsc::channel();
+    let (worker, input) = (*solver, input.to_string());
+    thread::spawn(move || {
+        // fails if the request stopped waiting
+        let _ = sender.send(worker.run_isolated(&input));
+    });
+    let result = match receiver.recv_timeout(deadline) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => {
+            let mut reply = failed(
+                format!("no answer within {} ms", deadline.as_millis()),
+                None,
+                None,
+            );
+            reply.status = 504;
+            return reply;
+        }
+        Err(RecvTimeoutError::Disconnected) => unreachable!("the worker catches panics"),
+    };
+    match result {
+        Ok(run) => Reply::ok(Solved {
+            day: solver.day,
+            part: solver.part,
+            answer: run.answer.clone(),
+            timing: Timing {
+                parse_us: run.parse.as_micros(),
+                solve_us: run.solve.as_micros(),
+                total_us: run.total().as_micros(),
+            },
+        }),
+        Err(e) if e.is::<Panicked>() => {
+            let mut reply = failed(e.to_string(), None, None);
+            reply.status = 500;
+            reply
+        }
+        Err(e) => {
+            let phase = e.downcast_ref::<Phase>().map(|phase| match phase {
+                Phase::Parse => "parse",
+                Phase::Solve => "solve",
+            });
+            let location = e
+                .chain()
+                .find_map(|e| e.downcast_ref::<ParseError>())
+                .and_then(|error| error.location.as_ref())
+                .map(|location| Location {
+                    line: location.line,
+                    column: location.column,
+                    snippet: location.snippet.clone(),
+                });
+            failed(format!("{:#}", e), phase, location)
+        }
+    }
+}
+
+/// Serves `solvers` until `server` is unblocked, each solve may take up to `deadline`
+pub fn serve(server: &Server, solvers: &[Solver], deadline: Duration) {
+    let content_type =
+        Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).expect("valid header");
+    for mut request in server.incoming_requests() {
+        let mut body = Vec::new();
+        let reply = match request
+            .as_reader()
+            .take(MAX_INPUT as u64 + 1)
+            .read_to_end(&mut body)
+        {
+            Err(e) => Reply::error(400, format!("reading the input: {}", e)),
+            Ok(_) if body.len() > MAX_INPUT => {
+                Reply::error(413, format!("inputs are limited to {} bytes", MAX_INPUT))
+            }
+            Ok(_) => handle(solvers, deadline, request.method(), request.url(), &body),
+        };
+        tracing::info!(
+            method = %request.method(),
+            url = request.url(),
+            status = reply.status,
+            "request"
+        );
+        let response = Response::from_string(reply.body.to_string())
+            .with_status_code(reply.status)
+            .with_header(content_type.clone());
+        if let Err(e) = request.respond(response) {
+            tracing::warn!("sending the response: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use std::sync::Arc;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+        const PART: u8 = 1;
+        type Input<'a> = Vec<u32>;
+        type Answer = u32;
+
+        fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+            let numbers = input.lines().enumerate().map(|(idx, line)| {
+                line.parse()
+                    .map_err(|_| ParseError::at_position(input, idx, 0, "expected a number"))
+            });
+            Ok(numbers.collect::<Result<_, _>>()?)
+        }
+
+        fn solve(input: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+            Ok(input.iter().sum())
+        }
+    }
+
+    struct Unfinished;
+
+    impl Solution for Unfinished {
+        const DAY: u8 = 1;
+        const PART: u8 = 2;
+        type Input<'a> = &'a str;
+        type Answer = u32;
+
+        fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+            Ok(input)
+        }
+
+        fn solve(_input: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+            panic!("unfinished")
+        }
+    }
+
+    /// Never finishes on `loop`
+    struct Endless;
+
+    impl Solution for Endless {
+        const DAY: u8 = 8;
+        const PART: u8 = 1;
+        type Input<'a> = &'a str;
+        type Answer = u32;
+
+        fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+            Ok(input)
+        }
+
+        fn solve(input: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+            if input == "loop" {
+                loop {
+                    std::thread::sleep(Duration::from_millis(10));
+                }
+            }
+            Ok(1)
+        }
+    }
+
+    fn solvers() -> Vec<Solver> {
+        vec![Solver::new::<Sum>(), Solver::new::<Unfinished>()]
+    }
+
+    #[test]
+    fn test_handle() {
+        let solvers = solvers();
+        let post =
+            |url: &str, body: &str| handle(&solvers, DEADLINE, &Method::Post, url, body.as_bytes());
+
+        assert_eq!(
+            Reply::ok(json!([{ "day": 1, "parts": [1, 2] }])),
+            handle(&solvers, DEADLINE, &Method::Get, "/days", b"")
+        );
+
+        let solved = post("/day/1/part/1?source=test", "1\n2\n");
+        assert_eq!(200, solved.status);
+        assert_eq!("3", solved.body["answer"]);
+        assert!(solved.body["timing"]["total_us"].is_u64());
+
+        let malformed = post("/day/1/part/1", "1\n23x\n");
+        assert_eq!(422, malformed.status);
+        assert_eq!("parse", malformed.body["phase"]);
+        assert_eq!(
+            json!({ "line": 2, "column": 1, "snippet": "23x" }),
+            malformed.body["location"]
+        );
+
+        let unfinished = post("/day/1/part/2", "");
+        assert_eq!(500, unfinished.status);
+        assert_eq!("panicked: unfinished", unfinished.body["error"]);
+
+        assert_eq!(404, post("/day/2/part/1", "").status);
+        assert_eq!(404, post("/day/one/part/1", "").status);
+        assert_eq!(404, post("/answers", "").status);
+        assert_eq!(
+            405,
+            handle(&solvers, DEADLINE, &Method::Get, "/day/1/part/1", b"").status
+        );
+        assert_eq!(
+            400,
+            handle(&solvers, DEADLINE, &Method::Post, "/day/1/part/1", &[0xff]).status
+        );
+    }
+
+    #[test]
+    fn test_localhost() -> anyhow::Result<()> {
+        let server =
+            Arc::new(Server::http("127.0.0.1:0").map_err(|e| anyhow::anyhow!("binding: {}", e))?);
+        let address = server
+            .server_addr()
+            .to_ip()
+            .expect("listening on a TCP socket");
+        let handle = {
+            let server = server.clone();
+            std::thread::spawn(move || serve(&server, &solvers(), DEADLINE))
+        };
+
+        let url = format!("http://{}", address);
+        let days = ureq::get(&format!("{}/days", url)).call();
+        let solved = ureq::post(&format!("{}/day/1/part/1", url)).send_string("40\n2\n");
+        let malformed = ureq::post(&format!("{}/day/1/part/1", url)).send_string("x");
+        server.unblock();
+        handle.join().expect("server panicked");
+
+        let days: Value = serde_json::from_str(&days?.into_string()?)?;
+        assert_eq!(json!([{ "day": 1, "parts": [1, 2] }]), days);
+        let solved = solved?;
+        assert_eq!("application/json", solved.content_type());
+        let solved: Value = serde_json::from_str(&solved.into_string()?)?;
+        assert_eq!("42", solved["answer"]);
+        match malformed {
+            Err(ureq::Error::Status(422, response)) => {
+                let failed: Value = serde_json::from_str(&response.into_string()?)?;
+                assert_eq!(1, failed["location"]["line"]);
+            }
+            other => panic!("expected a 422, got {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_localhost_deadline() -> anyhow::Result<()> {
+        let server =
+            Arc::new(Server::http("127.0.0.1:0").map_err(|e| anyhow::anyhow!("binding: {}", e))?);
+        let address = server
+            .server_addr()
+            .to_ip()
+            .expect("listening on a TCP socket");
+        let handle = {
+            let server = server.clone();
+            std::thread::spawn(move || {
+                serve(
+                    &server,
+                    &[Solver::new::<Endless>()],
+                    Duration::from_millis(200),
+                )
+            })
+        };
+
+        let url = format!("http://{}/day/8/part/1", address);
+        let endless = ureq::post(&url).send_string("loop");
+        let finished = ureq::post(&url).send_string("done");
+        server.unblock();
+        handle.join().expect("server panicked");
+
+        match endless {
+            Err(ureq::Error::Status(504, response)) => {
+                let failed: Value = serde_json::from_str(&response.into_string()?)?;
+                assert_eq!("no answer within 200 ms", failed["error"]);
+                assert_eq!(8, failed["day"]);
+            }
+            other => panic!("expected a 504, got {:?}", other),
+        }
+        assert_eq!(200, finished?.status());
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+//! Plain text tables of `aoc verify`, `aoc profile` and `aoc report`
+use std::fmt;
+
+/// Writes `header` and `rows`, one line each, columns two spaces apart
+///
+/// Every column is as wide as its widest cell. Columns listed in `right_aligned`
+/// are aligned right, like numbers and timings, the others left.
+pub fn render<const N: usize>(
+    f: &mut impl fmt::Write,
+    header: [&str; N],
+    rows: &[[String; N]],
+    right_aligned: &[usize],
+) -> fmt::Result {
+    let header = header.map(String::from);
+    let mut widths = [0; N];
+    for row in rows.iter().chain([&header]) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    for row in [&header].into_iter().chain(rows) {
+        let line = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(idx, (cell, width))| match right_aligned.contains(&idx) {
+                true => format!("{:>width$}", cell),
+                false => format!("{:<width$}", cell),
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(f, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() -> fmt::Result {
+        let rows = [
+            ["1".to_string(), "12.5µs".to_string(), "ok".to_string()],
+            ["25".to_string(), "3ms".to_string(), String::new()],
+        ];
+        let mut table = String::new();
+        render(&mut table, ["day", "time", "note"], &rows, &[0, 1])?;
+        assert_eq!("day    time  note\n  1  12.5µs  ok\n 25     3ms\n", table);
+        Ok(())
+    }
+}
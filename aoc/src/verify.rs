@@ -0,0 +1,211 @@
+use crate::table;
+use aoc_core::{input::Inputs, Answers, Solver};
+use std::{fmt, time::Duration};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+        };
+        f.pad(status)
+    }
+}
+
+/// Outcome of running one solver against one input
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub id: String,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub timing: Option<(Duration, Duration)>,
+    /// Why it didn't pass
+    pub note: Option<String>,
+}
+
+/// Runs every solver against every input it has an answer or a file for
+pub fn verify(solvers: &[Solver], inputs: &Inputs, answers: &Answers) -> Vec<Check> {
+    solvers
+        .iter()
+        .flat_map(|solver| {
+            let mut ids: Vec<String> = answers
+                .ids(solver.day)
+                .into_iter()
+                .map(String::from)
+                .collect();
+            ids.extend(inputs.ids(solver.day).unwrap_or_default());
+            ids.sort();
+            ids.dedup();
+            if ids.is_empty() {
+                ids.push(aoc_core::input::DEFAULT_ID.to_string());
+            }
+            ids.into_iter()
+                .map(|id| check(solver, &id, inputs, answers))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn check(solver: &Solver, id: &str, inputs: &Inputs, answers: &Answers) -> Check {
+    let mut check = Check {
+        day: solver.day,
+        part: solver.part,
+        id: id.to_string(),
+        status: Status::Missing,
+        answer: None,
+        timing: None,
+        note: None,
+    };
+    let input = match inputs.try_load(solver.day, id) {
+        Ok(Some(input)) => input,
+        Ok(None) => {
+            check.note = Some("no input".to_string());
+            return check;
+        }
+        Err(e) => {
+            check.status = Status::Fail;
+            check.note = Some(format!("{:#}", e));
+            return check;
+        }
+    };
+    let run = match solver.run_isolated(&input) {
+        Ok(run) => run,
+        Err(e) => {
+            check.status = Status::Fail;
+            check.note = Some(format!("{:#}", e));
+            return check;
+        }
+    };
+    check.timing = Some((run.parse, run.solve));
+    match answers.get(solver.day, solver.part, id) {
+        None => check.note = Some("no registered answer".to_string()),
+        Some(expected) if expected == run.answer => check.status = Status::Pass,
+        Some(expected) => {
+            check.status = Status::Fail;
+            check.note = Some(format!("expected {}", expected));
+        }
+    }
+    check.answer = Some(run.answer);
+    check
+}
+
+/// Checks rendered as a table with a summary line
+pub struct Table<'a>(pub &'a [Check]);
+
+impl fmt::Display for Table<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows: Vec<[String; 8]> = self
+            .0
+            .iter()
+            .map(|check| {
+                let (parse, solve) = check
+                    .timing
+                    .map(|(parse, solve)| (format!("{:.2?}", parse), format!("{:.2?}", solve)))
+                    .unwrap_or_default();
+                [
+                    check.day.to_string(),
+                    check.part.to_string(),
+                    check.id.clone(),
+                    check.status.to_string(),
+                    check.answer.clone().unwrap_or_default(),
+                    parse,
+                    solve,
+                    check.note.clone().unwrap_or_default(),
+                ]
+            })
+            .collect();
+        // numbers and timings are right aligned
+        table::render(
+            f,
+            [
+                "day", "part", "input", "status", "answer", "parse", "solve", "note",
+            ],
+            &rows,
+            &[0, 1, 5, 6],
+        )?;
+        let count = |status| self.0.iter().filter(|c| c.status == status).count();
+        write!(
+            f,
+            "{} passed, {} failed, {} missing",
+            count(Status::Pass),
+            count(Status::Fail),
+            count(Status::Missing)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        const DAY: u8 = 1;
+        const PART: u8 = 1;
+        type Input<'a> = usize;
+        type Answer = usize;
+
+        fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+            Ok(input.lines().count())
+        }
+
+        fn solve(input: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+            Ok(input)
+        }
+    }
+
+    #[test]
+    fn test_verify() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-verify-{}", std::process::id()));
+        let day = dir.join("day-01");
+        std::fs::create_dir_all(&day)?;
+        std::fs::write(day.join("input.txt"), "a\nb\nc")?;
+        std::fs::write(day.join("wrong.txt"), "a")?;
+        std::fs::write(day.join("new.txt"), "a\nb")?;
+        let answers = Answers::parse(
+            r#"
+[day-01.input]
+part1 = 3
+[day-01.wrong]
+part1 = 3
+[day-01.gone]
+part1 = 3
+"#,
+        )?;
+
+        let checks = verify(&[Solver::new::<Lines>()], &Inputs::new(&dir), &answers);
+        std::fs::remove_dir_all(&dir)?;
+
+        let statuses: Vec<(&str, Status)> = checks
+            .iter()
+            .map(|check| (check.id.as_str(), check.status))
+            .collect();
+        assert_eq!(
+            vec![
+                ("gone", Status::Missing),
+                ("input", Status::Pass),
+                ("new", Status::Missing),
+                ("wrong", Status::Fail),
+            ],
+            statuses
+        );
+        assert_eq!(Some("2"), checks[2].answer.as_deref());
+        assert_eq!(Some("expected 3"), checks[3].note.as_deref());
+        assert!(Table(&checks)
+            .to_string()
+            .ends_with("1 passed, 1 failed, 2 missing"));
+        Ok(())
+    }
+}
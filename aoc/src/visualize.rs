@@ -0,0 +1,103 @@
+//! `aoc run --visualize`: plays the frames of a day in the terminal
+use aoc_grid::render::{ansi, Frame, Frames};
+use std::{
+    io::{self, BufRead, Write},
+    time::Duration,
+};
+
+/// Clears the screen and hides the cursor while playing
+const START: &str = "\x1b[2J\x1b[?25l";
+/// Moves the cursor to the top left corner so every frame overwrites the previous one
+const HOME: &str = "\x1b[H";
+const END: &str = "\x1b[?25h";
+
+/// How the frames are played
+#[derive(Debug, Clone, Copy)]
+pub struct Playback {
+    /// Pause after every drawn frame
+    pub delay: Duration,
+    /// Only draw every `every`th frame, the last one is always drawn
+    pub every: usize,
+    /// Wait for Enter after every drawn frame instead of `delay`
+    pub step: bool,
+}
+
+/// Draws `frames` onto `out`, `keys` is read from in step mode. Returns the number of drawn frames
+pub fn play(
+    frames: Frames,
+    playback: Playback,
+    out: &mut impl Write,
+    keys: &mut impl BufRead,
+) -> io::Result<usize> {
+    let every = playback.every.max(1);
+    let mut frames = frames.enumerate().peekable();
+    let mut drawn = 0;
+    write!(out, "{}", START)?;
+    while let Some((idx, frame)) = frames.next() {
+        if !idx.is_multiple_of(every) && frames.peek().is_some() {
+            continue;
+        }
+        draw(out, &frame, idx)?;
+        drawn += 1;
+        if playback.step {
+            keys.read_line(&mut String::new())?;
+        } else {
+            std::thread::sleep(playback.delay);
+        }
+    }
+    write!(out, "{}", END)?;
+    out.flush()?;
+    Ok(drawn)
+}
+
+fn draw(out: &mut impl Write, frame: &Frame, idx: usize) -> io::Result<()> {
+    writeln!(out, "{}{}step {}\x1b[K", HOME, ansi(frame), idx)?;
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_grid::render::{Cell, Rgb};
+
+    fn frames(count: usize) -> Frames {
+        Box::new((0..count).map(|idx| {
+            let glyph = char::from_digit(idx as u32, 10).expect("single digit");
+            Frame::new(1, 1, Cell::new(glyph, Rgb::WHITE)).expect("not empty")
+        }))
+    }
+
+    #[test]
+    fn test_play() -> io::Result<()> {
+        let playback = Playback {
+            delay: Duration::ZERO,
+            every: 3,
+            step: false,
+        };
+        let mut out = Vec::new();
+        let drawn = play(frames(8), playback, &mut out, &mut io::empty())?;
+        let out = String::from_utf8(out).expect("UTF-8");
+
+        // 0, 3, 6 and the last one
+        assert_eq!(4, drawn);
+        assert_eq!(4, out.matches(HOME).count());
+        assert!(out.contains("7\x1b[0m\nstep 7"));
+        assert!(!out.contains("5\x1b[0m\n"));
+        assert!(out.starts_with(START) && out.ends_with(END));
+        Ok(())
+    }
+
+    #[test]
+    fn test_play_steps() -> io::Result<()> {
+        let playback = Playback {
+            delay: Duration::from_secs(60),
+            every: 1,
+            step: true,
+        };
+        let mut keys = io::Cursor::new("\n\n\n");
+        let drawn = play(frames(3), playback, &mut io::sink(), &mut keys)?;
+        assert_eq!(3, drawn);
+        assert_eq!(3, keys.position());
+        Ok(())
+    }
+}
@@ -1,7 +1,10 @@
-use {{crate_name}}::part1::process;
+use anyhow::Context;
+use aoc_core::{input, Solution};
+use {{crate_name}}::part1::Part1;
 
-fn main() {
-    let file = include_str!("../../input.txt");
-    let result = process(file);
+fn main() -> anyhow::Result<()> {
+    let file = input::from_args(Part1::DAY)?;
+    let result = Part1::process(&file).context("process part 1")?;
     println!("{}", result);
-}
\ No newline at end of file
+    Ok(())
+}
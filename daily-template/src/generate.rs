@@ -0,0 +1,11 @@
+//! Random inputs for day {{day}}
+//!
+//! `size` is the number of lines.
+use rand::Rng;
+
+/// Lines of a real input
+pub const DEFAULT_SIZE: usize = 1000;
+
+pub fn generate(_rng: &mut impl Rng, _size: usize) -> String {
+    todo!("day {{day}} - generator");
+}
@@ -1,2 +1,3 @@
+pub mod generate;
 pub mod part1;
-pub mod part2;
\ No newline at end of file
+pub mod part2;
@@ -1,15 +1,38 @@
-pub fn process(_input: &str) -> String {
-    todo!("day 01 - part 1");
+use aoc_core::Solution;
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = {{day}};
+    const PART: u8 = 1;
+    type Input<'a> = &'a str;
+    type Answer = String;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn solve(_input: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        todo!("day {{day}} - part 1");
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Example of the puzzle text
+    const EXAMPLE: &str = "";
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn test_process() -> anyhow::Result<()> {
+        assert_eq!("", Part1::process(EXAMPLE)?);
+        Ok(())
+    }
+
     #[test]
-    fn test_process() {
-        todo!("haven't built test yet");
-        let input = "";
-        assert_eq!("", process(input));
+    fn test_input() -> anyhow::Result<()> {
+        aoc_core::answers::check::<Part1>()
     }
 }
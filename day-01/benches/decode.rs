@@ -0,0 +1,84 @@
+//! Part 2 decoders on multi-megabyte generated documents
+//!
+//! `automaton` is the Aho-Corasick scan of [`day_01::automaton`], `match_indices`
+//! the previous solution: every spelling searched from both ends of every line.
+//! Run with `cargo bench -p day-01 --bench decode`.
+use aoc_core::generate::rng;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use day_01::{
+    calibration::{self, lines},
+    dictionary::Dictionary,
+    generate::generate,
+};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, IResult};
+use std::time::Duration;
+
+fn numberword(input: &str) -> IResult<&str, u32> {
+    alt((
+        value(1, alt((tag("1"), tag("one")))),
+        value(2, alt((tag("2"), tag("two")))),
+        value(3, alt((tag("3"), tag("three")))),
+        value(4, alt((tag("4"), tag("four")))),
+        value(5, alt((tag("5"), tag("five")))),
+        value(6, alt((tag("6"), tag("six")))),
+        value(7, alt((tag("7"), tag("seven")))),
+        value(8, alt((tag("8"), tag("eight")))),
+        value(9, alt((tag("9"), tag("nine")))),
+    ))(input)
+}
+
+const NEEDLE: [&str; 18] = [
+    "1", "2", "3", "4", "5", "6", "7", "8", "9", "one", "two", "three", "four", "five", "six",
+    "seven", "eight", "nine",
+];
+
+fn match_indices(line: &str) -> u32 {
+    let (_, left) = NEEDLE
+        .iter()
+        .filter_map(|needle| line.match_indices(needle).next())
+        .min_by_key(|x| x.0)
+        .expect("there should be at least one number");
+    let (_, left) = numberword(left).expect("All Needles are known");
+    let (_, right) = NEEDLE
+        .iter()
+        .filter_map(|needle| line.rmatch_indices(needle).next())
+        .max_by_key(|x| x.0)
+        .expect("there should be at least one number");
+    let (_, right) = numberword(right).expect("All Needles are known");
+    left * 10 + right
+}
+
+fn decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-01/decode");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+    for lines_count in [400_000, 1_600_000] {
+        let input = generate(&mut rng(0), lines_count);
+        let size = format!("{}MiB", input.len() >> 20);
+        group.throughput(Throughput::Bytes(input.len() as u64));
+
+        let digits = Dictionary::english().only_digits();
+        let sum = |input: &[u8]| -> u32 {
+            lines(input)
+                .map(|line| calibration::value(&digits, line).expect("generated lines have digits"))
+                .sum()
+        };
+        assert_eq!(
+            input.lines().map(match_indices).sum::<u32>(),
+            sum(input.as_bytes())
+        );
+
+        group.bench_with_input(BenchmarkId::new("automaton", &size), &input, |b, input| {
+            b.iter(|| sum(black_box(input.as_bytes())))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("match_indices", &size),
+            &input,
+            |b, input| b.iter(|| black_box(input).lines().map(match_indices).sum::<u32>()),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, decode);
+criterion_main!(benches);
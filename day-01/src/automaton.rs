@@ -0,0 +1,244 @@
+//! Aho-Corasick automaton finding the first and the last of a set of words
+//!
+//! Both directions are a DFA over bytes: every byte is one table lookup, however
+//! many words there are. The last word is found with a second automaton of the
+//! reversed words scanning from the end, so overlapping words like `eightwo`
+//! give `eight` from the front and `two` from the back.
+//!
+//! The first word is the one starting first, the last word the one ending last.
+//! If several start (end) there, the longest wins, so a word containing another
+//! one, like `achtzehn` and `zehn`, is found as a whole from both sides.
+use std::{cmp::Reverse, collections::VecDeque};
+
+/// Position of a word in the scanned bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub start: usize,
+    /// Exclusive
+    pub end: usize,
+    pub value: u32,
+}
+
+/// Words and their values, matched as bytes
+#[derive(Debug, Clone)]
+pub struct Automaton {
+    forward: Dfa,
+    backward: Dfa,
+    lengths: Vec<usize>,
+    values: Vec<u32>,
+    longest: usize,
+}
+
+impl Automaton {
+    /// Empty words are ignored. If a word is given twice, the first value is used
+    pub fn new<W: AsRef<[u8]>>(words: impl IntoIterator<Item = (W, u32)>) -> Self {
+        let mut forward = Vec::new();
+        let mut values = Vec::new();
+        for (word, value) in words {
+            let word = word.as_ref();
+            if !word.is_empty() {
+                forward.push(word.to_vec());
+                values.push(value);
+            }
+        }
+        let backward: Vec<Vec<u8>> = forward
+            .iter()
+            .map(|word| word.iter().rev().copied().collect())
+            .collect();
+        let lengths: Vec<usize> = forward.iter().map(Vec::len).collect();
+        Self {
+            forward: Dfa::new(&forward),
+            backward: Dfa::new(&backward),
+            longest: lengths.iter().copied().max().unwrap_or_default(),
+            lengths,
+            values,
+        }
+    }
+
+    /// The word starting first, the longest one if several start there
+    pub fn first(&self, haystack: &[u8]) -> Option<Match> {
+        let (start, word) = self.leftmost(&self.forward, haystack.iter().copied())?;
+        Some(self.found(word, start))
+    }
+
+    /// The word ending last, the longest one if several end there
+    pub fn last(&self, haystack: &[u8]) -> Option<Match> {
+        let (offset, word) = self.leftmost(&self.backward, haystack.iter().rev().copied())?;
+        let end = haystack.len() - offset;
+        Some(self.found(word, end - self.lengths[word]))
+    }
+
+    /// Where the word beginning first in scan order begins and which word it is
+    ///
+    /// Words are recognised where they end, so the scan goes on until no word
+    /// beginning before the best one so far can end anymore.
+    fn leftmost(&self, dfa: &Dfa, bytes: impl Iterator<Item = u8>) -> Option<(usize, usize)> {
+        let mut state = 0;
+        let mut best: Option<(usize, usize)> = None;
+        for (idx, byte) in bytes.enumerate() {
+            if best.is_some_and(|(begin, _)| idx >= begin + self.longest) {
+                break;
+            }
+            state = dfa.next(state, byte);
+            for &word in dfa.outputs(state) {
+                let candidate = (idx + 1 - self.lengths[word], word);
+                let key = |(begin, word): (usize, usize)| (begin, Reverse(self.lengths[word]));
+                if best.is_none_or(|best| key(candidate) < key(best)) {
+                    best = Some(candidate);
+                }
+            }
+        }
+        best
+    }
+
+    fn found(&self, word: usize, start: usize) -> Match {
+        Match {
+            start,
+            end: start + self.lengths[word],
+            value: self.values[word],
+        }
+    }
+}
+
+/// Aho-Corasick automaton with the failure links resolved into a full transition table
+#[derive(Debug, Clone)]
+struct Dfa {
+    /// Next state for every state and byte, state 0 is the root
+    next: Vec<[u32; 256]>,
+    /// Words ending in a state, its own word first, then those of its suffixes, longest first
+    outputs: Vec<Vec<usize>>,
+}
+
+impl Dfa {
+    const NONE: u32 = u32::MAX;
+
+    fn new(words: &[Vec<u8>]) -> Self {
+        let mut next = vec![[Self::NONE; 256]];
+        let mut outputs = vec![Vec::new()];
+        for (idx, word) in words.iter().enumerate() {
+            let mut state = 0;
+            for &byte in word {
+                if next[state][usize::from(byte)] == Self::NONE {
+                    next[state][usize::from(byte)] = next.len() as u32;
+                    next.push([Self::NONE; 256]);
+                    outputs.push(Vec::new());
+                }
+                state = next[state][usize::from(byte)] as usize;
+            }
+            outputs[state].push(idx);
+        }
+
+        // breadth first, so the failure state of a state is always done before it
+        let mut fail = vec![0; next.len()];
+        let mut queue = VecDeque::new();
+        for target in next[0].iter_mut() {
+            match *target {
+                Self::NONE => *target = 0,
+                child => queue.push_back(child as usize),
+            }
+        }
+        while let Some(state) = queue.pop_front() {
+            let suffix = outputs[fail[state]].clone();
+            outputs[state].extend(suffix);
+            let fallbacks = next[fail[state]];
+            for (target, fallback) in next[state].iter_mut().zip(fallbacks) {
+                match *target {
+                    Self::NONE => *target = fallback,
+                    child => {
+                        fail[child as usize] = fallback as usize;
+                        queue.push_back(child as usize);
+                    }
+                }
+            }
+        }
+        Self { next, outputs }
+    }
+
+    fn next(&self, state: usize, byte: u8) -> usize {
+        self.next[state][usize::from(byte)] as usize
+    }
+
+    fn outputs(&self, state: usize) -> &[usize] {
+        &self.outputs[state]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn automaton(words: &[&str]) -> Automaton {
+        Automaton::new(words.iter().zip(1..))
+    }
+
+    /// Tries every word at every position
+    fn naive(words: &[&str], haystack: &[u8]) -> Vec<Match> {
+        (0..haystack.len())
+            .flat_map(|start| {
+                words.iter().zip(1..).filter_map(move |(word, value)| {
+                    (!word.is_empty() && haystack[start..].starts_with(word.as_bytes())).then_some(
+                        Match {
+                            start,
+                            end: start + word.len(),
+                            value,
+                        },
+                    )
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_overlapping() {
+        let digits = automaton(&["one", "two", "three", "eight", "nine"]);
+        let (first, last) = (digits.first(b"eightwo"), digits.last(b"eightwo"));
+        assert_eq!(Some((0, 5, 4)), first.map(|m| (m.start, m.end, m.value)));
+        assert_eq!(Some((4, 7, 2)), last.map(|m| (m.start, m.end, m.value)));
+        assert_eq!(Some(1), digits.first(b"xtwone3").map(|m| m.start));
+        assert_eq!(Some(3), digits.last(b"xtwone3").map(|m| m.start));
+        assert_eq!(None, digits.first(b"zero"));
+        assert_eq!(None, digits.last(b""));
+    }
+
+    #[test]
+    fn test_contained_words() {
+        // `bc` is found before `abcd` ends, the scan has to go on to see `abcd` starts earlier
+        let words = automaton(&["abcd", "bc", "cd", "seven", "seventeen"]);
+        assert_eq!(Some(1), words.first(b"xabcdx").map(|m| m.value));
+        assert_eq!(Some(2), words.last(b"xabcx").map(|m| m.value));
+        assert_eq!(Some(1), words.last(b"xabcdx").map(|m| m.value));
+        assert_eq!(Some(5), words.first(b"seventeen").map(|m| m.value));
+        assert_eq!(Some(5), words.last(b"seventeen").map(|m| m.value));
+    }
+
+    #[test]
+    fn test_bytes() {
+        let words = Automaton::new([(&b"\xff\x00"[..], 1), (b"", 2), ("ä".as_bytes(), 3)]);
+        assert_eq!(Some(1), words.first(b"a\xff\x00").map(|m| m.value));
+        assert_eq!(Some(3), words.last("äa".as_bytes()).map(|m| m.value));
+        assert_eq!(None, words.first(b"abc"));
+        assert_eq!(
+            None,
+            Automaton::new(Vec::<(&str, u32)>::new()).first(b"abc")
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_against_naive(
+            words in prop::collection::vec("[abc]{1,4}", 1..6),
+            haystack in "[abcd]{0,30}",
+        ) {
+            let words: Vec<&str> = words.iter().map(String::as_str).collect();
+            let automaton = automaton(&words);
+            let matches = naive(&words, haystack.as_bytes());
+            // by start, then longest; of duplicate words the first
+            let key = |m: &&Match| (m.start, Reverse(m.end), m.value);
+            let first = matches.iter().min_by_key(key).copied();
+            let last = matches.iter().max_by_key(|m| (m.end, Reverse(m.start), Reverse(m.value))).copied();
+            prop_assert_eq!(first, automaton.first(haystack.as_bytes()));
+            prop_assert_eq!(last, automaton.last(haystack.as_bytes()));
+        }
+    }
+}
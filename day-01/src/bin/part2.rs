@@ -1,9 +1,10 @@
-use anyhow::{Ok, Result};
-use day_01::part1::process;
+use anyhow::Context;
+use aoc_core::{input, Solution};
+use day_01::part2::Part2;
 
-fn main() -> Result<()> {
-    let input = include_str!("../../input.txt");
-    let result = process(input)?;
+fn main() -> anyhow::Result<()> {
+    let file = input::from_args(Part2::DAY)?;
+    let result = Part2::process(&file).context("process part 2")?;
     println!("{}", result);
     Ok(())
 }
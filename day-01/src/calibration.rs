@@ -0,0 +1,331 @@
+//! Calibration values line by line, with where they were read from
+//!
+//! [`calibrate`] reports the first and last token of every line and the value
+//! they make, or [`NoDigit`] for a line without any. [`Annotated`] shows the
+//! tokens underlined below each line, to audit disputed lines.
+use crate::dictionary::Dictionary;
+use aoc_core::ParseError;
+use std::{fmt, ops::Range};
+
+/// Lines like [`str::lines`], without the line break and a trailing `\r`
+pub fn lines(input: &[u8]) -> impl Iterator<Item = &[u8]> {
+    spans(input).map(|span| &input[span])
+}
+
+/// Byte ranges of the lines, see [`lines`]
+fn spans(input: &[u8]) -> impl Iterator<Item = Range<usize>> + '_ {
+    let mut start = 0;
+    input
+        .split_inclusive(|&byte| byte == b'\n')
+        .map(move |line| {
+            let span = start..start + line.len();
+            start = span.end;
+            let line = line.strip_suffix(b"\n").unwrap_or(line);
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            span.start..span.start + line.len()
+        })
+}
+
+/// A digit or number word
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    /// Bytes of the token in its line
+    pub span: Range<usize>,
+    pub value: u32,
+}
+
+/// How the value of one line came about
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Calibration {
+    /// 1-based
+    pub line: usize,
+    /// Bytes of the line in the input, without the line break
+    pub span: Range<usize>,
+    pub first: Token,
+    pub last: Token,
+    pub value: u32,
+}
+
+/// A line without any digit or number word
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoDigit {
+    /// 1-based
+    pub line: usize,
+    /// Bytes of the line in the input, without the line break
+    pub span: Range<usize>,
+}
+
+impl NoDigit {
+    /// The error pointing at the line, `input` is what was calibrated
+    pub fn locate(&self, input: &[u8]) -> ParseError {
+        let line = String::from_utf8_lossy(&input[self.span.clone()]);
+        ParseError::in_line(&line, self.line - 1, 0, "no digit")
+    }
+}
+
+impl fmt::Display for NoDigit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {} has no digit", self.line)
+    }
+}
+
+impl std::error::Error for NoDigit {}
+
+/// First and last token of `line`, `None` if there is none
+pub fn tokens(dictionary: &Dictionary, line: &[u8]) -> Option<(Token, Token)> {
+    let automaton = dictionary.automaton();
+    let token = |found: crate::automaton::Match| Token {
+        span: found.start..found.end,
+        value: found.value,
+    };
+    Some((token(automaton.first(line)?), token(automaton.last(line)?)))
+}
+
+/// First digit times ten plus last digit, `None` if there is no digit
+///
+/// Words worth more than 9 contribute their first or last digit, see [`crate::dictionary`].
+pub fn value(dictionary: &Dictionary, line: &[u8]) -> Option<u32> {
+    let (first, last) = tokens(dictionary, line)?;
+    Some(combine(first.value, last.value))
+}
+
+fn combine(first: u32, last: u32) -> u32 {
+    let mut first = first;
+    while first >= 10 {
+        first /= 10;
+    }
+    first * 10 + last % 10
+}
+
+/// Every line of `input`, in order
+pub fn calibrate<'a>(
+    dictionary: &'a Dictionary,
+    input: &'a [u8],
+) -> impl Iterator<Item = Result<Calibration, NoDigit>> + 'a {
+    spans(input).enumerate().map(move |(idx, span)| {
+        let line = idx + 1;
+        match tokens(dictionary, &input[span.clone()]) {
+            Some((first, last)) => Ok(Calibration {
+                line,
+                span,
+                value: combine(first.value, last.value),
+                first,
+                last,
+            }),
+            None => Err(NoDigit { line, span }),
+        }
+    })
+}
+
+/// Sum of the values of all lines
+///
+/// A line without a digit fails with the [`NoDigit`] and, as its source, the
+/// [`ParseError`] pointing at the line.
+pub fn sum(dictionary: &Dictionary, input: &[u8]) -> anyhow::Result<u32> {
+    calibrate(dictionary, input)
+        .map(|calibration| match calibration {
+            Ok(calibration) => Ok(calibration.value),
+            Err(no_digit) => Err(anyhow::Error::new(no_digit.locate(input)).context(no_digit)),
+        })
+        .sum()
+}
+
+/// Lines with their value and the first (`^`) and last (`~`) token underlined
+///
+/// Where both tokens cover a byte, like a line with a single digit, it is marked `*`.
+///
+/// ```text
+///    1  82 | eightwo
+///          | ^^^^*~~
+///    2   - | xyz
+///          | no digit
+/// ```
+///
+/// The summary line counts all `lines`, also those `errors_only` leaves out.
+pub struct Annotated<'a> {
+    pub input: &'a [u8],
+    pub lines: &'a [Result<Calibration, NoDigit>],
+    /// Only show the lines without a digit
+    pub errors_only: bool,
+}
+
+impl fmt::Display for Annotated<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let shown = self
+            .lines
+            .iter()
+            .filter(|result| !self.errors_only || result.is_err());
+        for result in shown {
+            let (line, span) = match result {
+                Ok(calibration) => (calibration.line, &calibration.span),
+                Err(no_digit) => (no_digit.line, &no_digit.span),
+            };
+            let value = result
+                .as_ref()
+                .map_or("-".to_string(), |calibration| calibration.value.to_string());
+            let bytes = &self.input[span.clone()];
+            // one column per char, invalid UTF-8 becomes U+FFFD
+            let mut text = String::new();
+            let mut columns = Vec::new();
+            let mut offset = 0;
+            for chunk in bytes.utf8_chunks() {
+                for (idx, c) in chunk.valid().char_indices() {
+                    text.push(c);
+                    columns.push(offset + idx);
+                }
+                offset += chunk.valid().len();
+                if !chunk.invalid().is_empty() {
+                    text.push(char::REPLACEMENT_CHARACTER);
+                    columns.push(offset);
+                    offset += chunk.invalid().len();
+                }
+            }
+            let text = format!("{:>4} {:>3} | {}", line, value, text);
+            writeln!(f, "{}", text.trim_end())?;
+            let markers: String = match result {
+                Ok(calibration) => columns
+                    .iter()
+                    .map(|byte| {
+                        match (
+                            calibration.first.span.contains(byte),
+                            calibration.last.span.contains(byte),
+                        ) {
+                            (true, true) => '*',
+                            (true, false) => '^',
+                            (false, true) => '~',
+                            (false, false) => ' ',
+                        }
+                    })
+                    .collect(),
+                Err(_) => "no digit".to_string(),
+            };
+            writeln!(f, "         | {}", markers.trim_end())?;
+        }
+        let failed = self.lines.iter().filter(|result| result.is_err()).count();
+        write!(f, "{} lines, {} without a digit", self.lines.len(), failed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calibrate() -> anyhow::Result<()> {
+        let digits = Dictionary::english().only_digits();
+        let input = b"eightwo\r\nxyz\n7\n";
+        let lines: Vec<_> = calibrate(&digits, input).collect();
+        assert_eq!(
+            vec![
+                Ok(Calibration {
+                    line: 1,
+                    span: 0..7,
+                    first: Token {
+                        span: 0..5,
+                        value: 8
+                    },
+                    last: Token {
+                        span: 4..7,
+                        value: 2
+                    },
+                    value: 82,
+                }),
+                Err(NoDigit {
+                    line: 2,
+                    span: 9..12
+                }),
+                Ok(Calibration {
+                    line: 3,
+                    span: 13..14,
+                    first: Token {
+                        span: 0..1,
+                        value: 7
+                    },
+                    last: Token {
+                        span: 0..1,
+                        value: 7
+                    },
+                    value: 77,
+                }),
+            ],
+            lines
+        );
+        assert_eq!(82 + 77, sum(&digits, b"eightwo\n7")?);
+
+        let error = sum(&digits, input).expect_err("line 2");
+        assert_eq!(lines[1].as_ref().err(), error.downcast_ref::<NoDigit>());
+        assert_eq!("line 2 has no digit", error.to_string());
+        assert_eq!(
+            "no digit at line 2, column 1\n  |\n2 | xyz\n  | ^",
+            error
+                .chain()
+                .find_map(|e| e.downcast_ref::<ParseError>())
+                .expect("located")
+                .to_string()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotated() {
+        let english = Dictionary::english();
+        let input = "eightwo\nxyz\ntwentyfünf3\nnineteen\n7".as_bytes();
+        let lines: Vec<_> = calibrate(&english, input).collect();
+        assert_eq!(
+            "   1  82 | eightwo
+         | ^^^^*~~
+   2   - | xyz
+         | no digit
+   3  23 | twentyfünf3
+         | ^^^^^^    ~
+   4  19 | nineteen
+         | ********
+   5  77 | 7
+         | *
+5 lines, 1 without a digit",
+            Annotated {
+                input,
+                lines: &lines,
+                errors_only: false,
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_annotated_errors_only() {
+        let digits = Dictionary::english().only_digits();
+        let input = b"1\nxyz\n2two\n\n";
+        let lines: Vec<_> = calibrate(&digits, input).collect();
+        assert_eq!(
+            "   2   - | xyz
+         | no digit
+   4   - |
+         | no digit
+4 lines, 2 without a digit",
+            Annotated {
+                input,
+                lines: &lines,
+                errors_only: true,
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let digits = Dictionary::english().only_digits();
+        let input = b"\xffone\xfe2";
+        let lines: Vec<_> = calibrate(&digits, input).collect();
+        assert_eq!(Some(12), lines[0].as_ref().ok().map(|c| c.value));
+        assert_eq!(
+            "   1  12 | \u{fffd}one\u{fffd}2\n         |  ^^^ ~\n1 lines, 0 without a digit",
+            Annotated {
+                input,
+                lines: &lines,
+                errors_only: false,
+            }
+            .to_string()
+        );
+    }
+}
@@ -0,0 +1,287 @@
+//! Number words the calibration decoder looks for besides the digits `1` to `9`
+//!
+//! A word stands for its value written in decimal. The first token of a line
+//! gives the first digit of its value, the last token the last digit:
+//!
+//! - `twelve` alone is 12, `twenty` alone is 20
+//! - `twentythree` is 23, `twenty` first and `three` last
+//! - `eighteen` is 18, not 8: of the tokens starting first the longest wins,
+//!   and of those ending last as well, so `achtzehn` isn't read as `zehn`
+//!
+//! The built-in tables cover 1 to 19 and the tens. Only whole table words are
+//! matched, compounds aren't decoded: `einundzwanzig` reads as `zwanzig`, 20,
+//! since `ein` isn't a table word. Words are matched case sensitive.
+use crate::automaton::Automaton;
+use anyhow::{bail, ensure, Context};
+use std::{ops::RangeInclusive, path::Path};
+
+const ENGLISH: [(&str, u32); 27] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+    ("ten", 10),
+    ("eleven", 11),
+    ("twelve", 12),
+    ("thirteen", 13),
+    ("fourteen", 14),
+    ("fifteen", 15),
+    ("sixteen", 16),
+    ("seventeen", 17),
+    ("eighteen", 18),
+    ("nineteen", 19),
+    ("twenty", 20),
+    ("thirty", 30),
+    ("forty", 40),
+    ("fifty", 50),
+    ("sixty", 60),
+    ("seventy", 70),
+    ("eighty", 80),
+    ("ninety", 90),
+];
+
+const GERMAN: [(&str, u32); 27] = [
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fünf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+    ("zehn", 10),
+    ("elf", 11),
+    ("zwölf", 12),
+    ("dreizehn", 13),
+    ("vierzehn", 14),
+    ("fünfzehn", 15),
+    ("sechzehn", 16),
+    ("siebzehn", 17),
+    ("achtzehn", 18),
+    ("neunzehn", 19),
+    ("zwanzig", 20),
+    ("dreißig", 30),
+    ("vierzig", 40),
+    ("fünfzig", 50),
+    ("sechzig", 60),
+    ("siebzig", 70),
+    ("achtzig", 80),
+    ("neunzig", 90),
+];
+
+/// 70, 80 and 90 are compounds in French, they are listed whole
+const FRENCH: [(&str, u32); 27] = [
+    ("un", 1),
+    ("deux", 2),
+    ("trois", 3),
+    ("quatre", 4),
+    ("cinq", 5),
+    ("six", 6),
+    ("sept", 7),
+    ("huit", 8),
+    ("neuf", 9),
+    ("dix", 10),
+    ("onze", 11),
+    ("douze", 12),
+    ("treize", 13),
+    ("quatorze", 14),
+    ("quinze", 15),
+    ("seize", 16),
+    ("dix-sept", 17),
+    ("dix-huit", 18),
+    ("dix-neuf", 19),
+    ("vingt", 20),
+    ("trente", 30),
+    ("quarante", 40),
+    ("cinquante", 50),
+    ("soixante", 60),
+    ("soixante-dix", 70),
+    ("quatre-vingt", 80),
+    ("quatre-vingt-dix", 90),
+];
+
+/// Number words and their values, plus the digits `1` to `9`
+#[derive(Debug, Clone)]
+pub struct Dictionary {
+    words: Vec<(String, u32)>,
+    automaton: Automaton,
+}
+
+impl Dictionary {
+    /// Fails on empty words, words containing a digit and words given twice
+    pub fn new<W: Into<String>>(words: impl IntoIterator<Item = (W, u32)>) -> anyhow::Result<Self> {
+        Self::with_digits(words, 1..=9)
+    }
+
+    /// Only the digits `0` to `9`, no words, part 1's rule
+    pub fn digits() -> Self {
+        Self::with_digits(Vec::<(String, u32)>::new(), 0..=9).expect("no words to check")
+    }
+
+    fn with_digits<W: Into<String>>(
+        words: impl IntoIterator<Item = (W, u32)>,
+        digits: RangeInclusive<u32>,
+    ) -> anyhow::Result<Self> {
+        let mut checked: Vec<(String, u32)> = Vec::new();
+        for (word, value) in words {
+            let word = word.into();
+            ensure!(!word.is_empty(), "empty word for {}", value);
+            // a digit inside a word would be a token of its own as well
+            ensure!(
+                !word.bytes().any(|byte| byte.is_ascii_digit()),
+                "`{}` contains a digit",
+                word
+            );
+            if let Some((_, other)) = checked.iter().find(|(known, _)| *known == word) {
+                bail!("`{}` is both {} and {}", word, other, value);
+            }
+            checked.push((word, value));
+        }
+        let digits = digits.map(|digit| (digit.to_string(), digit));
+        let automaton = Automaton::new(digits.chain(checked.iter().cloned()));
+        Ok(Self {
+            words: checked,
+            automaton,
+        })
+    }
+
+    fn builtin(table: &[(&str, u32)]) -> Self {
+        Self::new(table.iter().copied()).expect("built-in tables are valid")
+    }
+
+    /// `one` to `nineteen` and the tens
+    pub fn english() -> Self {
+        Self::builtin(&ENGLISH)
+    }
+
+    /// `eins` to `neunzehn` and the tens
+    pub fn german() -> Self {
+        Self::builtin(&GERMAN)
+    }
+
+    /// `un` to `dix-neuf` and the tens
+    pub fn french() -> Self {
+        Self::builtin(&FRENCH)
+    }
+
+    /// Only the words for `1` to `9`, the puzzle's rule
+    pub fn only_digits(&self) -> Self {
+        Self::new(
+            self.words
+                .iter()
+                .filter(|(_, value)| (1..=9).contains(value))
+                .cloned(),
+        )
+        .expect("a subset of a valid dictionary")
+    }
+
+    /// One `word = value` per line, blank lines and lines starting with `#` are skipped
+    ///
+    /// ```text
+    /// # Dutch
+    /// een = 1
+    /// twee = 2
+    /// ```
+    pub fn parse(input: &str) -> anyhow::Result<Self> {
+        let mut words = Vec::new();
+        for (idx, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let entry = line
+                .split_once('=')
+                .context("expected `word = value`")
+                .and_then(|(word, value)| {
+                    let value = value.trim();
+                    let value: u32 = value
+                        .parse()
+                        .with_context(|| format!("`{}` isn't a number", value))?;
+                    Ok((word.trim().to_string(), value))
+                })
+                .with_context(|| format!("line {}", idx + 1))?;
+            words.push(entry);
+        }
+        Self::new(words)
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let input =
+            std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        Self::parse(&input).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    /// The words without the digits
+    pub fn words(&self) -> &[(String, u32)] {
+        &self.words
+    }
+
+    pub(crate) fn automaton(&self) -> &Automaton {
+        &self.automaton
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin() {
+        for dictionary in [
+            Dictionary::english(),
+            Dictionary::german(),
+            Dictionary::french(),
+        ] {
+            let mut values: Vec<u32> = dictionary.words().iter().map(|(_, value)| *value).collect();
+            values.sort_unstable();
+            let expected: Vec<u32> = (1..20).chain((2..10).map(|tens| tens * 10)).collect();
+            assert_eq!(expected, values);
+            assert_eq!(9, dictionary.only_digits().words().len());
+        }
+    }
+
+    #[test]
+    fn test_parse() -> anyhow::Result<()> {
+        let dutch = Dictionary::parse("# Dutch\n\neen = 1\n  twee=2 \ntwaalf = 12\n")?;
+        assert_eq!(
+            vec![
+                ("een".to_string(), 1),
+                ("twee".to_string(), 2),
+                ("twaalf".to_string(), 12)
+            ],
+            dutch.words()
+        );
+        let error = |input| format!("{:#}", Dictionary::parse(input).expect_err(input));
+        assert_eq!("line 2: expected `word = value`", error("een = 1\ntwee"));
+        assert_eq!(
+            "line 1: `two` isn't a number: invalid digit found in string",
+            error("twee = two")
+        );
+        assert_eq!("`een` is both 1 and 11", error("een = 1\neen = 11"));
+        assert_eq!("empty word for 3", error(" = 3"));
+        assert_eq!("`7` contains a digit", error("7 = 7"));
+        assert_eq!("`zwei2` contains a digit", error("zwei2 = 2"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_load() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-dictionary-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join("nl.txt");
+        std::fs::write(&path, "een = 1\nfout\n")?;
+        let loaded = Dictionary::load(&path);
+        let missing = Dictionary::load(&dir.join("missing.txt"));
+        std::fs::remove_dir_all(&dir)?;
+
+        assert!(format!("{:#}", loaded.expect_err("line 2")).contains("nl.txt: line 2"));
+        assert!(missing.is_err());
+        Ok(())
+    }
+}
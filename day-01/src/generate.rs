@@ -0,0 +1,49 @@
+//! Random calibration documents
+//!
+//! `size` is the number of lines.
+use rand::{seq::SliceRandom, Rng};
+
+/// Lines of a real input
+pub const DEFAULT_SIZE: usize = 1000;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        // part 1 needs at least one real digit per line
+        let mut tokens = vec![rng.gen_range('1'..='9').to_string()];
+        for _ in 0..rng.gen_range(0..8) {
+            let token = match rng.gen_range(0..3) {
+                0 => rng.gen_range('1'..='9').to_string(),
+                1 => WORDS.choose(rng).expect("not empty").to_string(),
+                _ => (0..rng.gen_range(1..=4))
+                    .map(|_| rng.gen_range('a'..='z'))
+                    .collect(),
+            };
+            tokens.push(token);
+        }
+        tokens.shuffle(rng);
+        input.push_str(&tokens.concat());
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+    use aoc_core::{generate::rng, Solution};
+
+    #[test]
+    fn test_generated_input() -> anyhow::Result<()> {
+        let input = generate(&mut rng(1), 100);
+        assert_eq!(input.lines().count(), 100);
+        Part1::process(&input)?;
+        Part2::process(&input)?;
+        Ok(())
+    }
+}
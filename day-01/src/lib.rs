@@ -1,2 +1,6 @@
+pub mod automaton;
+pub mod calibration;
+pub mod dictionary;
+pub mod generate;
 pub mod part1;
 pub mod part2;
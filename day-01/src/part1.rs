@@ -1,23 +1,22 @@
+use crate::{calibration, dictionary::Dictionary};
 use anyhow::Result;
+use aoc_core::Solution;
 
-pub fn process(input: &str) -> Result<String> {
-    let mut sum: u32 = 0;
-    input.lines().for_each(|line| {
-        let result = [
-            line.chars()
-                .find(char::is_ascii_digit)
-                .unwrap()
-                .to_digit(10)
-                .unwrap(),
-            line.chars()
-                .rfind(char::is_ascii_digit)
-                .unwrap()
-                .to_digit(10)
-                .unwrap(),
-        ];
-        sum += result[0] * 10 + result[1];
-    });
-    Ok(sum.to_string())
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 1;
+    const PART: u8 = 1;
+    type Input<'a> = &'a str;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn solve(input: Self::Input<'_>) -> Result<Self::Answer> {
+        calibration::sum(&Dictionary::digits(), input.as_bytes())
+    }
 }
 
 #[cfg(test)]
@@ -31,13 +30,19 @@ treb7uchet";
 
     #[test]
     fn test_part_1() {
-        let result = "142";
-        assert_eq!(process(INPUT).unwrap(), result)
+        assert_eq!(Part1::process(INPUT).unwrap(), 142)
+    }
+
+    #[test]
+    fn test_no_digit() {
+        assert_eq!(0, Part1::process("").unwrap());
+        assert_eq!(0, Part1::process("a0b\n").unwrap());
+        let e = Part1::process("1abc2\none\n").expect_err("no digit");
+        assert_eq!("line 2 has no digit", e.to_string());
     }
 
     #[test]
-    fn test_part_1_real_data() {
-        let data = include_str!("../input.txt");
-        assert_eq!(process(data).unwrap(), "54338")
+    fn test_part_1_real_data() -> anyhow::Result<()> {
+        aoc_core::answers::check::<Part1>()
     }
 }
@@ -1,66 +1,28 @@
-use anyhow::{Context, Result};
-use nom::{branch::alt, bytes::complete::tag, combinator::value, IResult};
+use crate::{calibration, dictionary::Dictionary};
+use anyhow::Result;
+use aoc_core::Solution;
 
-fn numberword(input: &str) -> IResult<&str, u32> {
-    let (rest, number): (&str, u32) = alt((
-        value(1, alt((tag("1"), tag("one")))),
-        value(2, alt((tag("2"), tag("two")))),
-        value(3, alt((tag("3"), tag("three")))),
-        value(4, alt((tag("4"), tag("four")))),
-        value(5, alt((tag("5"), tag("five")))),
-        value(6, alt((tag("6"), tag("six")))),
-        value(7, alt((tag("7"), tag("seven")))),
-        value(8, alt((tag("8"), tag("eight")))),
-        value(9, alt((tag("9"), tag("nine")))),
-    ))(input)?;
-    Ok((rest, number))
-}
+pub struct Part2;
 
-const NEEDLE: [&str; 18] = [
-    "1", "2", "3", "4", "5", "6", "7", "8", "9", "one", "two", "three", "four", "five", "six",
-    "seven", "eight", "nine",
-];
-fn parse_line(line: &str) -> u32 {
-    let (_, left) = NEEDLE
-        .iter()
-        .filter_map(|needle| {
-            let matches: Vec<(usize, &str)> = line.match_indices(needle).collect();
-            if !matches.is_empty() {
-                Some(matches[0])
-            } else {
-                None
-            }
-        })
-        .min_by_key(|x| x.0)
-        .context("Finding first number")
-        .expect("there should be at least one number");
-    let (_, left) = numberword(left).expect("All Needles are known");
+impl Solution for Part2 {
+    const DAY: u8 = 1;
+    const PART: u8 = 2;
+    type Input<'a> = &'a [u8];
+    type Answer = u32;
 
-    // since the words can be overlapping a rmatch is needed to find the last number
-    let (_, right) = NEEDLE
-        .iter()
-        .filter_map(|needle| {
-            let matches: Vec<(usize, &str)> = line.rmatch_indices(needle).collect();
-            if !matches.is_empty() {
-                Some(matches[0])
-            } else {
-                None
-            }
-        })
-        .max_by_key(|x| x.0)
-        .context("Finding last number")
-        .expect("there should be at least one number");
-    let (_, right) = numberword(right).expect("All Needles are known");
-    left * 10 + right
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input.as_bytes())
+    }
 
-pub fn process(input: &str) -> Result<String> {
-    Ok(input.lines().map(parse_line).sum::<u32>().to_string())
+    fn solve(input: Self::Input<'_>) -> Result<Self::Answer> {
+        calibration::sum(&Dictionary::english().only_digits(), input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::calibration::{lines, value};
 
     #[test]
     fn test_part_2() {
@@ -72,13 +34,63 @@ xtwone3four
 zoneight234
 7pqrstsixteen
 eighthree";
-        let result = "364";
-        assert_eq!(process(input2).unwrap(), result)
+        assert_eq!(Part2::process(input2).unwrap(), 364)
+    }
+
+    #[test]
+    fn test_overlapping_words() {
+        let digits = Dictionary::english().only_digits();
+        assert_eq!(Some(82), value(&digits, b"eightwo"));
+        assert_eq!(Some(21), value(&digits, b"twone"));
+        assert_eq!(Some(18), value(&digits, b"oneight"));
+        assert_eq!(Some(79), value(&digits, b"sevenine"));
+        assert_eq!(Some(77), value(&digits, b"seventeen"));
+    }
+
+    #[test]
+    fn test_multi_digit_words() {
+        let english = Dictionary::english();
+        assert_eq!(Some(12), value(&english, b"twelve"));
+        assert_eq!(Some(20), value(&english, b"xtwentyx"));
+        assert_eq!(Some(23), value(&english, b"twentythree"));
+        assert_eq!(Some(17), value(&english, b"seventeen"));
+        assert_eq!(Some(10), value(&english, b"1ninety"));
+        assert_eq!(Some(15), value(&english, b"thirteenfive"));
+
+        let german = Dictionary::german();
+        assert_eq!(Some(18), value(&german, b"achtzehn"));
+        assert_eq!(Some(12), value(&german, "zwölf".as_bytes()));
+        // compounds aren't decoded, `ein` isn't a word of the table
+        assert_eq!(Some(20), value(&german, b"einundzwanzig"));
+        assert_eq!(None, value(&german, b"seven"));
+
+        let french = Dictionary::french();
+        assert_eq!(Some(17), value(&french, b"dix-sept"));
+        assert_eq!(Some(90), value(&french, b"quatre-vingt-dix"));
+        assert_eq!(Some(41), value(&french, b"quatreun"));
+    }
+
+    #[test]
+    fn test_calibration_sum() -> anyhow::Result<()> {
+        let german = Dictionary::german();
+        assert_eq!(
+            12 + 38 + 55,
+            calibration::sum(&german, "zwölf\ndrei8\n5\n".as_bytes())?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lines() {
+        let lines: Vec<&[u8]> = lines(b"one\r\n\ntwo\n").collect();
+        assert_eq!(vec![&b"one"[..], b"", b"two"], lines);
+        assert_eq!(0, Part2::process("").unwrap());
+        let e = Part2::process("one\nxyz\n").expect_err("no digit");
+        assert_eq!("line 2 has no digit", e.to_string());
     }
 
     #[test]
-    fn test_part_2_real_data() {
-        let data = include_str!("../input.txt");
-        assert_eq!(process(data).unwrap(), "53389")
+    fn test_part_2_real_data() -> anyhow::Result<()> {
+        aoc_core::answers::check::<Part2>()
     }
 }
@@ -0,0 +1,104 @@
+//! Cubes by colour, for any colours a game names
+use std::collections::BTreeMap;
+
+/// How many cubes of each colour, colours not in the bag have none
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bag {
+    cubes: BTreeMap<String, u32>,
+}
+
+impl Bag {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, color: &str) -> u32 {
+        self.cubes.get(color).copied().unwrap_or_default()
+    }
+
+    pub fn insert(&mut self, color: impl Into<String>, number: u32) {
+        self.cubes.insert(color.into(), number);
+    }
+
+    /// Colours and their cubes, by colour name
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.cubes
+            .iter()
+            .map(|(color, &number)| (color.as_str(), number))
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = &str> {
+        self.cubes.keys().map(String::as_str)
+    }
+
+    /// Cubes of all colours together
+    pub fn total(&self) -> u32 {
+        self.cubes.values().sum()
+    }
+
+    /// At least as many cubes of every colour as `other`
+    pub fn holds(&self, other: &Bag) -> bool {
+        other
+            .iter()
+            .all(|(color, number)| number <= self.get(color))
+    }
+
+    /// The larger number of cubes of every colour of both bags
+    pub fn max(&self, other: &Bag) -> Bag {
+        let mut max = self.clone();
+        for (color, number) in other.iter() {
+            if number > max.get(color) {
+                max.insert(color, number);
+            }
+        }
+        max
+    }
+
+    /// Product of the cubes of `colors`, the puzzle's power if they are red, green and blue
+    ///
+    /// A colour the bag doesn't have makes it 0.
+    pub fn power<'a>(&self, colors: impl IntoIterator<Item = &'a str>) -> u64 {
+        colors
+            .into_iter()
+            .map(|color| u64::from(self.get(color)))
+            .product()
+    }
+}
+
+/// Adds up the cubes of a colour given more than once
+impl<S: Into<String>> FromIterator<(S, u32)> for Bag {
+    fn from_iter<T: IntoIterator<Item = (S, u32)>>(iter: T) -> Self {
+        let mut cubes = BTreeMap::new();
+        for (color, number) in iter {
+            *cubes.entry(color.into()).or_default() += number;
+        }
+        Self { cubes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bag() {
+        let bag = Bag::from_iter([("red", 12), ("green", 13), ("blue", 14)]);
+        let drawn = Bag::from_iter([("red", 4), ("blue", 3), ("red", 8)]);
+        assert_eq!(12, drawn.get("red"));
+        assert_eq!(0, drawn.get("green"));
+        assert_eq!(15, drawn.total());
+        assert!(bag.holds(&drawn));
+        assert!(!drawn.holds(&bag));
+        assert!(!bag.holds(&Bag::from_iter([("violet", 1)])));
+        assert!(bag.holds(&Bag::from_iter([("violet", 0)])));
+
+        let max = drawn.max(&Bag::from_iter([("blue", 1), ("violet", 2)]));
+        assert_eq!(
+            vec![("blue", 3), ("red", 12), ("violet", 2)],
+            max.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(72, max.power(max.colors()));
+        assert_eq!(0, max.power(["red", "green"]));
+        assert_eq!(1, Bag::new().power([]));
+    }
+}
@@ -1,9 +1,10 @@
 use anyhow::Context;
-use day_02::part2::process;
+use aoc_core::{input, Solution};
+use day_02::part2::Part2;
 
 fn main() -> anyhow::Result<()> {
-    let file = include_str!("../../input2.txt");
-    let result = process(file).context("process part 1")?;
+    let file = input::from_args(Part2::DAY)?;
+    let result = Part2::process(&file).context("process part 2")?;
     println!("{}", result);
     Ok(())
 }
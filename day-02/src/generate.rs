@@ -0,0 +1,43 @@
+//! Random cube games
+//!
+//! `size` is the number of games.
+use rand::{seq::SliceRandom, Rng};
+
+/// Games of a real input
+pub const DEFAULT_SIZE: usize = 100;
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut input = String::new();
+    for id in 1..=size {
+        let rounds: Vec<String> = (0..rng.gen_range(1..=6))
+            .map(|_| {
+                let count = rng.gen_range(1..=COLORS.len());
+                COLORS
+                    .choose_multiple(rng, count)
+                    .map(|color| format!("{} {}", rng.gen_range(1..=20), color))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        input.push_str(&format!("Game {}: {}\n", id, rounds.join("; ")));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+    use aoc_core::{generate::rng, Solution};
+
+    #[test]
+    fn test_generated_input() -> anyhow::Result<()> {
+        let input = generate(&mut rng(1), 50);
+        assert_eq!(Part1::parse(&input)?.len(), 50);
+        Part1::process(&input)?;
+        Part2::process(&input)?;
+        Ok(())
+    }
+}
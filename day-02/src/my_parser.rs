@@ -1,87 +1,68 @@
+use crate::bag::Bag;
 use nom::{
-    branch::alt,
     bytes::complete::tag,
-    character::complete,
-    combinator::value,
+    character::complete::{self, alpha1, line_ending},
     multi::separated_list1,
     sequence::{preceded, separated_pair, terminated},
     IResult,
 };
+use std::collections::BTreeSet;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Move {
     pub number: u32,
-    pub color: Color,
-}
-
-#[derive(Debug, Clone, Copy)]
-pub enum Color {
-    Blue,
-    Red,
-    Green,
+    pub color: String,
 }
 
 #[derive(Debug)]
 pub struct Game {
     pub id: u32,
     pub moves: Vec<Vec<Move>>,
-    pub max: MaxVal,
-}
-
-#[derive(Default, Clone, Copy, Debug)]
-pub struct MaxVal {
-    pub red: u32,
-    pub green: u32,
-    pub blue: u32,
 }
 
 impl Game {
-    pub fn is_possible(&self, max: MaxVal) -> bool {
-        self.max.red <= max.red && self.max.green <= max.green && self.max.blue <= max.blue
+    /// The cubes drawn in every round
+    pub fn rounds(&self) -> impl Iterator<Item = Bag> + '_ {
+        self.moves.iter().map(|moveset| {
+            moveset
+                .iter()
+                .map(|single_move| (single_move.color.as_str(), single_move.number))
+                .collect()
+        })
     }
 
-    pub fn initialize_max(&mut self) {
-        let mut new_max: MaxVal = Default::default();
-        for moveset in &self.moves {
-            for single_move in moveset {
-                match single_move.color {
-                    Color::Red => {
-                        if single_move.number > new_max.red {
-                            new_max.red = single_move.number
-                        }
-                    }
-                    Color::Blue => {
-                        if single_move.number > new_max.blue {
-                            new_max.blue = single_move.number
-                        }
-                    }
-                    Color::Green => {
-                        if single_move.number > new_max.green {
-                            new_max.green = single_move.number
-                        }
-                    }
-                }
-            }
-        }
-        self.max = new_max
+    /// The fewest cubes of every colour the game could have been played with
+    pub fn minimal_bag(&self) -> Bag {
+        self.rounds().fold(Bag::new(), |bag, round| bag.max(&round))
+    }
+
+    /// Power of the minimal bag over the colours the game names
+    pub fn power(&self) -> u64 {
+        let bag = self.minimal_bag();
+        bag.power(bag.colors())
+    }
+
+    pub fn is_possible(&self, bag: &Bag) -> bool {
+        self.rounds().all(|round| bag.holds(&round))
     }
 }
 
-fn color_parser(input: &str) -> IResult<&str, Color> {
-    alt((
-        value(Color::Red, tag("red")),
-        value(Color::Green, tag("green")),
-        value(Color::Blue, tag("blue")),
-    ))(input)
+/// Every colour named in `games`
+pub fn colors(games: &[Game]) -> BTreeSet<&str> {
+    games
+        .iter()
+        .flat_map(|game| game.moves.iter().flatten())
+        .map(|single_move| single_move.color.as_str())
+        .collect()
 }
 
 fn move_parser(input: &str) -> IResult<&str, Move> {
-    let (input, mv) = separated_pair(complete::u32, tag(" "), color_parser)(input)?;
+    let (input, mv) = separated_pair(complete::u32, tag(" "), alpha1)(input)?;
     Ok((
         input,
         Move {
             number: mv.0,
-            color: mv.1,
+            color: mv.1.to_string(),
         },
     ))
 }
@@ -91,12 +72,61 @@ pub fn line_parser(input: &str) -> IResult<&str, Game> {
         terminated(preceded(tag("Game "), complete::u32), tag(": "))(input)?;
     let (input, moves): (&str, Vec<Vec<Move>>) =
         separated_list1(tag("; "), separated_list1(tag(", "), move_parser))(input)?;
-    Ok((
-        input,
-        Game {
-            id: number,
-            moves,
-            max: Default::default(),
-        },
-    ))
+    Ok((input, Game { id: number, moves }))
+}
+
+pub fn games_parser(input: &str) -> IResult<&str, Vec<Game>> {
+    separated_list1(line_ending, line_parser)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate;
+    use aoc_core::{
+        generate::{mutate, rng},
+        parse::parse_all,
+    };
+
+    /// Parsing may fail but must neither panic nor hang, also when rendering the error
+    fn parse(input: &str) {
+        if let Err(error) = parse_all(input, games_parser) {
+            error.to_string();
+        }
+    }
+
+    #[test]
+    fn test_any_colors() -> anyhow::Result<()> {
+        let games = parse_all(
+            "Game 1: 3 blue, 4 violet; 2 blue, 1 red\nGame 7: 5 ochre",
+            games_parser,
+        )?;
+        assert_eq!(
+            Bag::from_iter([("blue", 3), ("violet", 4), ("red", 1)]),
+            games[0].minimal_bag()
+        );
+        assert_eq!(
+            vec!["blue", "ochre", "red", "violet"],
+            colors(&games).into_iter().collect::<Vec<_>>()
+        );
+        let bag = Bag::from_iter([("blue", 3), ("violet", 4), ("red", 1)]);
+        assert!(games[0].is_possible(&bag));
+        assert!(!games[1].is_possible(&bag));
+        assert_eq!(12, games[0].power());
+        assert_eq!(5, games[1].power());
+        assert_eq!(0, games[1].minimal_bag().power(colors(&games)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fuzz_bytes() {
+        bolero::check!().for_each(|bytes: &[u8]| parse(&String::from_utf8_lossy(bytes)));
+    }
+
+    #[test]
+    fn test_fuzz_generated() {
+        bolero::check!()
+            .with_type::<(u64, Vec<(u16, u8)>)>()
+            .for_each(|(seed, edits)| parse(&mutate(&generate(&mut rng(*seed), 5), edits)));
+    }
 }
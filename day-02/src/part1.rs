@@ -1,30 +1,41 @@
-use super::my_parser::{line_parser, Game, MaxVal};
-use nom::{character::complete::line_ending, multi::separated_list1};
+use super::{
+    bag::Bag,
+    my_parser::{games_parser, Game},
+};
+use aoc_core::{parse::parse_all, Solution};
+use tracing::debug;
 
-pub fn process(input: &str) -> anyhow::Result<String> {
-    let (_input, mut games): (&str, Vec<Game>) =
-        separated_list1(line_ending, line_parser)(input).unwrap();
+pub struct Part1;
 
-    let test_case = MaxVal {
-        red: 12,
-        green: 13,
-        blue: 14,
-    };
+impl Solution for Part1 {
+    const DAY: u8 = 2;
+    const PART: u8 = 1;
+    type Input<'a> = Vec<Game>;
+    type Answer = u32;
 
-    let result: u32 = games
-        .iter_mut()
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse_all(input, games_parser)?)
+    }
+
+    fn solve(games: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(possible_games(&games))
+    }
+}
+
+fn possible_games(games: &[Game]) -> u32 {
+    let test_case = Bag::from_iter([("red", 12), ("green", 13), ("blue", 14)]);
+
+    games
+        .iter()
         .filter_map(|game| {
-            game.initialize_max();
-            if game.is_possible(test_case) {
-                println!("{:?}", game);
+            if game.is_possible(&test_case) {
+                debug!(?game, "possible");
                 Some(game.id)
             } else {
                 None
             }
         })
-        .sum();
-
-    Ok(result.to_string())
+        .sum()
 }
 
 #[cfg(test)]
@@ -35,7 +46,7 @@ mod tests {
 
     #[test]
     fn test_process() -> anyhow::Result<()> {
-        assert_eq!("8", process(TESTINPUT)?);
+        assert_eq!(8, Part1::process(TESTINPUT)?);
         Ok(())
     }
 }
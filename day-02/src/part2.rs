@@ -1,24 +1,29 @@
-use super::my_parser::{line_parser, Game, MaxVal};
-use nom::{character::complete::line_ending, multi::separated_list1};
+use super::my_parser::{games_parser, Game};
+use aoc_core::{parse::parse_all, Solution};
 
-impl MaxVal {
-    fn power(self) -> u32 {
-        self.blue * self.green * self.red
-    }
-}
+/// Colours the puzzle's power is taken over
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+pub struct Part2;
 
-pub fn process(input: &str) -> anyhow::Result<String> {
-    let (_input, mut games): (&str, Vec<Game>) =
-        separated_list1(line_ending, line_parser)(input).unwrap();
+impl Solution for Part2 {
+    const DAY: u8 = 2;
+    const PART: u8 = 2;
+    type Input<'a> = Vec<Game>;
+    type Answer = u64;
 
-    let result: u32 = games
-        .iter_mut()
-        .map(|game| {
-            game.initialize_max();
-            game.max.power()
-        })
-        .sum();
-    Ok(result.to_string())
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse_all(input, games_parser)?)
+    }
+
+    /// The power of a game is taken over red, green and blue, a game without one of them has
+    /// none. Other colours don't count, [`Game::power`] takes those of the game instead.
+    fn solve(games: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(games
+            .iter()
+            .map(|game| game.minimal_bag().power(COLORS))
+            .sum())
+    }
 }
 
 #[cfg(test)]
@@ -32,7 +37,9 @@ Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
 Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
-        assert_eq!("2286", process(input)?);
+        assert_eq!(2286, Part2::process(input)?);
+        let extra = format!("{}\nGame 6: 2 violet, 1 red, 2 green; 3 blue", input);
+        assert_eq!(2286 + 6, Part2::process(&extra)?);
         Ok(())
     }
 }
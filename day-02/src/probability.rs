@@ -0,0 +1,158 @@
+//! How likely a game is with a given bag
+//!
+//! The cubes of a round are drawn at once, without replacement, and put back
+//! before the next round. A round showing `k` cubes of each colour `c` out of a
+//! bag of `n` cubes, `n_c` of them of colour `c`, has the multivariate
+//! hypergeometric probability
+//!
+//! ```text
+//! C(n_red, k_red) * C(n_green, k_green) * ... / C(n, k_red + k_green + ...)
+//! ```
+//!
+//! and the rounds of a game are independent. Everything is exact, as [`BigRational`].
+use crate::{bag::Bag, my_parser::Game};
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Zero};
+
+/// Ways to choose `k` of `n`
+fn binomial(n: u32, k: u32) -> BigInt {
+    if k > n {
+        return BigInt::zero();
+    }
+    let k = k.min(n - k);
+    (0..k).fold(BigInt::one(), |ways, i| ways * (n - i) / (i + 1))
+}
+
+/// Probability of drawing exactly the cubes of `round` from `bag`
+///
+/// 0 if the bag hasn't enough cubes of a colour.
+pub fn round(bag: &Bag, round: &Bag) -> BigRational {
+    if !bag.holds(round) {
+        return BigRational::zero();
+    }
+    let favourable = round
+        .iter()
+        .map(|(color, number)| binomial(bag.get(color), number))
+        .product();
+    BigRational::new(favourable, binomial(bag.total(), round.total()))
+}
+
+/// Probability of every round of `game`, in order
+pub fn rounds<'a>(bag: &'a Bag, game: &'a Game) -> impl Iterator<Item = BigRational> + 'a {
+    game.rounds().map(|drawn| round(bag, &drawn))
+}
+
+/// Probability of all rounds of `game`
+pub fn likelihood(bag: &Bag, game: &Game) -> BigRational {
+    rounds(bag, game).product()
+}
+
+/// A candidate bag and how likely it makes the game
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranked<'a> {
+    pub bag: &'a Bag,
+    pub likelihood: BigRational,
+}
+
+/// `candidates` by the likelihood of `game`, most likely first
+///
+/// Equally likely bags keep their order, bags that can't have been played with come last.
+pub fn rank<'a>(game: &Game, candidates: &'a [Bag]) -> Vec<Ranked<'a>> {
+    let mut ranked: Vec<Ranked> = candidates
+        .iter()
+        .map(|bag| Ranked {
+            bag,
+            likelihood: likelihood(bag, game),
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.likelihood.cmp(&a.likelihood));
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::my_parser::line_parser;
+    use aoc_core::parse::parse_all;
+
+    fn ratio(numer: i64, denom: i64) -> BigRational {
+        BigRational::new(numer.into(), denom.into())
+    }
+
+    fn game(input: &str) -> Game {
+        parse_all(input, line_parser).expect("a valid game")
+    }
+
+    #[test]
+    fn test_round() {
+        let bag = Bag::from_iter([("red", 2), ("blue", 1)]);
+        let drawn = |cubes: &[(&str, u32)]| round(&bag, &cubes.iter().copied().collect());
+        assert_eq!(ratio(2, 3), drawn(&[("red", 1)]));
+        assert_eq!(ratio(2, 3), drawn(&[("red", 1), ("blue", 1)]));
+        assert_eq!(ratio(1, 3), drawn(&[("red", 2)]));
+        assert_eq!(ratio(1, 1), drawn(&[("red", 2), ("blue", 1)]));
+        assert_eq!(ratio(0, 1), drawn(&[("red", 3)]));
+        assert_eq!(ratio(0, 1), drawn(&[("green", 1)]));
+        assert_eq!(ratio(1, 1), drawn(&[("red", 0)]));
+    }
+
+    #[test]
+    fn test_rounds_sum_to_one() {
+        // every way to draw 4 cubes out of 3 red, 2 green and 4 blue
+        let bag = Bag::from_iter([("red", 3), ("green", 2), ("blue", 4)]);
+        let mut total = BigRational::zero();
+        for red in 0..=4 {
+            for green in 0..=4 - red {
+                let drawn =
+                    Bag::from_iter([("red", red), ("green", green), ("blue", 4 - red - green)]);
+                total += round(&bag, &drawn);
+            }
+        }
+        assert_eq!(BigRational::one(), total);
+    }
+
+    #[test]
+    fn test_likelihood() {
+        let bag = Bag::from_iter([("red", 12), ("green", 13), ("blue", 14)]);
+        let first = game("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green");
+        // C(14,3) C(12,4) / C(39,7), C(12,1) C(13,2) C(14,6) / C(39,9), C(13,2) / C(39,2)
+        let expected = [
+            ratio(364 * 495, 15_380_937),
+            ratio(12 * 78 * 3003, 211_915_132),
+            ratio(78, 741),
+        ];
+        assert_eq!(expected.to_vec(), rounds(&bag, &first).collect::<Vec<_>>());
+        assert_eq!(
+            expected.iter().product::<BigRational>(),
+            likelihood(&bag, &first)
+        );
+
+        let impossible = game("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green");
+        assert!(likelihood(&bag, &impossible).is_zero());
+    }
+
+    #[test]
+    fn test_rank() {
+        let played = game("Game 1: 1 red; 1 red; 1 blue");
+        let candidates = [
+            Bag::from_iter([("red", 3)]),
+            Bag::from_iter([("red", 1), ("blue", 1)]),
+            Bag::from_iter([("red", 2), ("blue", 1)]),
+            Bag::from_iter([("blue", 1), ("red", 1)]),
+        ];
+        let ranked: Vec<_> = rank(&played, &candidates)
+            .into_iter()
+            .map(|ranked| (ranked.bag.clone(), ranked.likelihood))
+            .collect();
+        assert_eq!(
+            vec![
+                (candidates[2].clone(), ratio(4, 27)),
+                (candidates[1].clone(), ratio(1, 8)),
+                (candidates[3].clone(), ratio(1, 8)),
+                (candidates[0].clone(), ratio(0, 1)),
+            ],
+            ranked
+        );
+    }
+}
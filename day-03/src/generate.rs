@@ -0,0 +1,51 @@
+//! Random engine schematics
+//!
+//! `size` is the width and height of the schematic.
+use rand::{seq::SliceRandom, Rng};
+
+/// Width and height of a real input
+pub const DEFAULT_SIZE: usize = 140;
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '=', '%', '@', '&', '-'];
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        while line.len() < size {
+            let left = size - line.len();
+            match rng.gen_range(0..10) {
+                0..=1 => {
+                    let digits = rng.gen_range(1..=3).min(left);
+                    line.push(rng.gen_range('1'..='9'));
+                    (1..digits).for_each(|_| line.push(rng.gen_range('0'..='9')));
+                    // numbers on the same line are never glued together
+                    if line.len() < size {
+                        line.push('.');
+                    }
+                }
+                2 => line.push(*SYMBOLS.choose(rng).expect("not empty")),
+                _ => line.push('.'),
+            }
+        }
+        input.push_str(&line);
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+    use aoc_core::{generate::rng, Solution};
+
+    #[test]
+    fn test_generated_input() -> anyhow::Result<()> {
+        let input = generate(&mut rng(1), 40);
+        assert!(input.lines().all(|line| line.len() == 40));
+        Part1::process(&input)?;
+        Part2::process(&input)?;
+        Ok(())
+    }
+}
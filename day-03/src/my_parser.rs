@@ -109,3 +109,11 @@ pub fn line_parser(input: &str, line_number: usize) -> Parts {
         value_parts,
     }
 }
+
+pub fn my_parser(input: &str) -> Vec<Parts> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_number, line)| line_parser(line, line_number))
+        .collect()
+}
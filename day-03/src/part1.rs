@@ -1,4 +1,5 @@
-use super::my_parser::{line_parser, CharPart, Parts, ValuePart};
+use super::my_parser::{my_parser, CharPart, Parts, ValuePart};
+use aoc_core::Solution;
 
 impl ValuePart {
     pub fn touches(&self, other: &[CharPart]) -> bool {
@@ -41,12 +42,24 @@ impl ValuePart {
     }
 }
 
-pub fn process(input: &str) -> anyhow::Result<String> {
-    let parts: Vec<Parts> = input
-        .lines()
-        .enumerate()
-        .map(|(line_number, line)| line_parser(line, line_number))
-        .collect();
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 3;
+    const PART: u8 = 1;
+    type Input<'a> = Vec<Parts>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(my_parser(input))
+    }
+
+    fn solve(parts: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(part_numbers(&parts))
+    }
+}
+
+fn part_numbers(parts: &[Parts]) -> usize {
     let value_parts: Vec<ValuePart> = parts
         .iter()
         .map(|x| x.value_parts.clone())
@@ -64,12 +77,10 @@ pub fn process(input: &str) -> anyhow::Result<String> {
                 v.extend(x.iter());
                 v
             });
-    let t = value_parts
+    value_parts
         .iter()
         .filter(|&part| part.touches(&char_parts) || part.in_range_of(&value_parts))
-        .fold(0, |acc, part| acc + part.value);
-
-    Ok(t.to_string())
+        .fold(0, |acc, part| acc + part.value)
 }
 
 #[cfg(test)]
@@ -88,7 +99,7 @@ mod tests {
 ......755.
 ...$.*....
 .664.598..";
-        assert_eq!("4361", process(input)?);
+        assert_eq!(4361, Part1::process(input)?);
         Ok(())
     }
 }
@@ -1,11 +1,24 @@
-use super::my_parser::{line_parser, CharPart, Parts, ValuePart};
+use super::my_parser::{my_parser, CharPart, Parts, ValuePart};
+use aoc_core::Solution;
 
-pub fn process(input: &str) -> anyhow::Result<String> {
-    let parts: Vec<Parts> = input
-        .lines()
-        .enumerate()
-        .map(|(line_number, line)| line_parser(line, line_number))
-        .collect();
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 3;
+    const PART: u8 = 2;
+    type Input<'a> = Vec<Parts>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(my_parser(input))
+    }
+
+    fn solve(parts: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(gear_ratios(&parts))
+    }
+}
+
+fn gear_ratios(parts: &[Parts]) -> usize {
     // select 'gears'
     let char_parts: Vec<CharPart> =
         parts
@@ -27,7 +40,7 @@ pub fn process(input: &str) -> anyhow::Result<String> {
         .filter(|part| part.touches(&char_parts))
         .collect();
     // for all 'gears' check if they touch exactly 2 value parts and calculate the gear ratio
-    let result: usize = char_parts
+    char_parts
         .iter()
         .filter_map(|gear| {
             let touching: Vec<ValuePart> = value_parts
@@ -41,8 +54,7 @@ pub fn process(input: &str) -> anyhow::Result<String> {
                 None
             }
         })
-        .sum();
-    Ok(result.to_string())
+        .sum()
 }
 
 #[cfg(test)]
@@ -61,7 +73,7 @@ mod tests {
 ......755.
 ...$.*....
 .664.598..";
-        assert_eq!("467835", process(input)?);
+        assert_eq!(467835, Part2::process(input)?);
         Ok(())
     }
 }
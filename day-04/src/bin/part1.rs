@@ -1,9 +1,10 @@
 use anyhow::Context;
-use day_04::part1::process;
+use aoc_core::{input, Solution};
+use day_04::part1::Part1;
 
 fn main() -> anyhow::Result<()> {
-    let file = include_str!("../../input.txt");
-    let result = process(file).context("process part 1")?;
+    let file = input::from_args(Part1::DAY)?;
+    let result = Part1::process(&file).context("process part 1")?;
     println!("{}", result);
     Ok(())
 }
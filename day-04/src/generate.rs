@@ -0,0 +1,72 @@
+//! Random scratchcards
+//!
+//! `size` is the number of cards. Cards are grouped in short runs which
+//! only win copies within the run, like in the real input. Otherwise the
+//! number of copies grows exponentially and overflows for big sizes.
+use rand::{
+    seq::{index::sample, SliceRandom},
+    Rng,
+};
+
+/// Cards of a real input
+pub const DEFAULT_SIZE: usize = 200;
+
+const WINNING: usize = 10;
+const NUMBERS: usize = 25;
+
+fn card(rng: &mut impl Rng, number: usize, wins: usize) -> String {
+    // numbers are distinct per card, the first ones are the winning ones
+    let mut pool: Vec<usize> = sample(rng, 99, WINNING + NUMBERS - wins)
+        .into_iter()
+        .map(|n| n + 1)
+        .collect();
+    let winning = pool[..WINNING].to_vec();
+    pool.splice(WINNING..WINNING, winning[..wins].iter().copied());
+    let mut numbers = pool.split_off(WINNING);
+    numbers.shuffle(rng);
+    let format = |numbers: &[usize]| {
+        numbers
+            .iter()
+            .map(|n| format!("{:>2}", n))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    format!(
+        "Card {:>3}: {} | {}\n",
+        number,
+        format(&winning),
+        format(&numbers)
+    )
+}
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut input = String::new();
+    let mut number = 1;
+    while number <= size {
+        let run = rng.gen_range(5..=25).min(size - number + 1);
+        for position in 0..run {
+            let wins = rng.gen_range(0..=WINNING.min(run - position - 1));
+            input.push_str(&card(rng, number, wins));
+            number += 1;
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+    use aoc_core::{generate::rng, Solution};
+
+    #[test]
+    fn test_generated_input() -> anyhow::Result<()> {
+        let input = generate(&mut rng(1), 1000);
+        let cards = Part1::parse(&input)?;
+        assert_eq!(cards.len(), 1000);
+        assert!(cards.iter().all(|card| card.numbers.len() == NUMBERS));
+        Part1::process(&input)?;
+        Part2::process(&input)?;
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+pub mod generate;
 pub mod my_parser;
 pub mod part1;
 pub mod part2;
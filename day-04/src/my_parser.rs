@@ -36,3 +36,32 @@ pub fn line_parser(input: &str) -> IResult<&str, Card> {
 pub fn my_parser(input: &str) -> IResult<&str, Vec<Card>> {
     separated_list1(line_ending, line_parser)(input)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate;
+    use aoc_core::{
+        generate::{mutate, rng},
+        parse::parse_all,
+    };
+
+    /// Parsing may fail but must neither panic nor hang, also when rendering the error
+    fn parse(input: &str) {
+        if let Err(error) = parse_all(input, my_parser) {
+            error.to_string();
+        }
+    }
+
+    #[test]
+    fn test_fuzz_bytes() {
+        bolero::check!().for_each(|bytes: &[u8]| parse(&String::from_utf8_lossy(bytes)));
+    }
+
+    #[test]
+    fn test_fuzz_generated() {
+        bolero::check!()
+            .with_type::<(u64, Vec<(u16, u8)>)>()
+            .for_each(|(seed, edits)| parse(&mutate(&generate(&mut rng(*seed), 5), edits)));
+    }
+}
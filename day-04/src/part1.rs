@@ -1,25 +1,42 @@
 use super::my_parser::*;
+use aoc_core::{parse::parse_all, Solution};
+use tracing::trace;
 
-pub fn process(input: &str) -> anyhow::Result<String> {
-    let (_input, cards) = my_parser(input).unwrap();
-    let result: u32 = cards
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 4;
+    const PART: u8 = 1;
+    type Input<'a> = Vec<Card>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse_all(input, my_parser)?)
+    }
+
+    fn solve(cards: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(points(&cards))
+    }
+}
+
+fn points(cards: &[Card]) -> u32 {
+    cards
         .iter()
-        // .inspect(|x| println!("{:?}", x))
         .filter_map(|card| {
             let num_correct = card
                 .numbers
                 .iter()
                 .filter(|number| card.winning.contains(number))
-                // .inspect(|x| println!("{:?}", x))
+                .inspect(|number| trace!(card = card.number, number, "winning number"))
                 .count();
+            trace!(card = card.number, num_correct, "scored card");
             if num_correct >= 1 {
                 Some(u32::pow(2, (num_correct - 1) as u32))
             } else {
                 None
             }
         })
-        .sum();
-    Ok(result.to_string())
+        .sum()
 }
 
 #[cfg(test)]
@@ -34,7 +51,7 @@ Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
 Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
 Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
-        assert_eq!("13", process(input)?);
+        assert_eq!(13, Part1::process(input)?);
         Ok(())
     }
 }
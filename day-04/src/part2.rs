@@ -1,7 +1,25 @@
 use super::my_parser::*;
+use aoc_core::{parse::parse_all, Solution};
+use tracing::trace;
 
-pub fn process(input: &str) -> anyhow::Result<String> {
-    let (_input, cards) = my_parser(input).unwrap();
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 4;
+    const PART: u8 = 2;
+    type Input<'a> = Vec<Card>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse_all(input, my_parser)?)
+    }
+
+    fn solve(cards: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(scratchcards(&cards))
+    }
+}
+
+fn scratchcards(cards: &[Card]) -> usize {
     let max_cards = cards.len();
     let mut played: Vec<usize> = vec![1; max_cards];
     for (card_idx, card) in cards.iter().enumerate() {
@@ -10,11 +28,17 @@ pub fn process(input: &str) -> anyhow::Result<String> {
             .iter()
             .filter(|number| card.winning.contains(number))
             .count();
+        trace!(
+            card = card.number,
+            winning_count,
+            copies = played[card_idx],
+            "played card"
+        );
         for future_idx in (card_idx + 1)..=max_cards.min(winning_count + card_idx) {
             played[future_idx] += played[card_idx];
         }
     }
-    Ok(played.iter().sum::<usize>().to_string())
+    played.iter().sum()
 }
 
 #[cfg(test)]
@@ -30,14 +54,12 @@ Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
 Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
 ";
-        assert_eq!("30", process(input)?);
+        assert_eq!(30, Part2::process(input)?);
         Ok(())
     }
 
     #[test]
     fn test_input() -> anyhow::Result<()> {
-        let input = include_str!("../input.txt");
-        assert_eq!("5037841", process(input)?);
-        Ok(())
+        aoc_core::answers::check::<Part2>()
     }
 }
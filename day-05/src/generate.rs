@@ -0,0 +1,101 @@
+//! Random almanacs
+//!
+//! `size` is the number of seeds part 2 has to look at, the seed ranges add
+//! up to it. Every map shuffles ranges of `0..2^32` like the real maps do.
+use rand::{seq::SliceRandom, Rng};
+
+/// Seeds part 2 has to check, a real input has billions
+pub const DEFAULT_SIZE: usize = 10_000_000;
+
+const UNIVERSE: u64 = 1 << 32;
+const SEED_RANGES: usize = 10;
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Splits `total` into at most `parts` positive lengths
+fn split(rng: &mut impl Rng, total: u64, parts: usize) -> Vec<u64> {
+    let mut cuts: Vec<u64> = (1..parts.min(total as usize))
+        .map(|_| rng.gen_range(1..total))
+        .collect();
+    cuts.sort_unstable();
+    cuts.dedup();
+    cuts.push(total);
+    let mut start = 0;
+    cuts.into_iter()
+        .map(|cut| {
+            let len = cut - start;
+            start = cut;
+            len
+        })
+        .collect()
+}
+
+/// A map moving the ranges of `0..UNIVERSE` around, as `destination source length` lines
+fn map(rng: &mut impl Rng) -> Vec<String> {
+    let ranges = rng.gen_range(10..=40);
+    let lengths = split(rng, UNIVERSE, ranges);
+    let mut sources: Vec<(u64, u64)> = lengths
+        .iter()
+        .scan(0, |start, len| {
+            *start += len;
+            Some((*start - len, *len))
+        })
+        .collect();
+    sources.shuffle(rng);
+    let mut destination = 0;
+    let mut lines: Vec<String> = sources
+        .into_iter()
+        .map(|(source, len)| {
+            destination += len;
+            format!("{} {} {}", destination - len, source, len)
+        })
+        .collect();
+    lines.shuffle(rng);
+    lines
+}
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = (size as u64).clamp(1, UNIVERSE / 2);
+    let seeds: Vec<String> = split(rng, size, SEED_RANGES)
+        .into_iter()
+        .map(|len| format!("{} {}", rng.gen_range(0..=UNIVERSE - len), len))
+        .collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+    for name in MAPS {
+        input.push_str(&format!("\n{} map:\n", name));
+        for line in map(rng) {
+            input.push_str(&line);
+            input.push('\n');
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+    use aoc_core::{generate::rng, Solution};
+
+    #[test]
+    fn test_generated_input() -> anyhow::Result<()> {
+        let input = generate(&mut rng(1), 1000);
+        let almanac = Part1::parse(&input)?;
+        assert!(almanac.seeds.len().is_multiple_of(2));
+        assert_eq!(
+            almanac.seeds.chunks(2).map(|range| range[1]).sum::<u64>(),
+            1000
+        );
+        Part1::process(&input)?;
+        Part2::process(&input)?;
+        Ok(())
+    }
+}
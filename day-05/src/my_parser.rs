@@ -2,7 +2,7 @@ use nom::{
     bytes::complete::tag,
     character::complete::{self, line_ending},
     multi::separated_list1,
-    sequence::{preceded, terminated},
+    sequence::{preceded, terminated, tuple},
     IResult, Parser,
 };
 
@@ -32,15 +32,15 @@ pub struct Almanac {
 fn map_parser(input: &str) -> IResult<&str, Vec<MapData>> {
     separated_list1(
         line_ending,
-        separated_list1(tag(" "), complete::u64).map({
-            |x| {
-                assert_eq!(x.len(), 3);
-                MapData {
-                    destination_start: x[0],
-                    source_start: x[1],
-                    len: x[2],
-                }
-            }
+        tuple((
+            complete::u64,
+            preceded(tag(" "), complete::u64),
+            preceded(tag(" "), complete::u64),
+        ))
+        .map(|(destination_start, source_start, len)| MapData {
+            destination_start,
+            source_start,
+            len,
         }),
     )(input)
 }
@@ -90,3 +90,32 @@ pub fn almanac_parser(input: &str) -> IResult<&str, Almanac> {
         },
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate;
+    use aoc_core::{
+        generate::{mutate, rng},
+        parse::parse_all,
+    };
+
+    /// Parsing may fail but must neither panic nor hang, also when rendering the error
+    fn parse(input: &str) {
+        if let Err(error) = parse_all(input, almanac_parser) {
+            error.to_string();
+        }
+    }
+
+    #[test]
+    fn test_fuzz_bytes() {
+        bolero::check!().for_each(|bytes: &[u8]| parse(&String::from_utf8_lossy(bytes)));
+    }
+
+    #[test]
+    fn test_fuzz_generated() {
+        bolero::check!()
+            .with_type::<(u64, Vec<(u16, u8)>)>()
+            .for_each(|(seed, edits)| parse(&mutate(&generate(&mut rng(*seed), 5), edits)));
+    }
+}
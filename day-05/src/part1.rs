@@ -1,4 +1,5 @@
-use crate::my_parser::{almanac_parser, MapData};
+use crate::my_parser::{almanac_parser, Almanac, MapData};
+use aoc_core::{parse::parse_all, Solution};
 
 pub fn mapping(num: u64, map: &[MapData]) -> u64 {
     let mut found: u64 = num;
@@ -12,9 +13,25 @@ pub fn mapping(num: u64, map: &[MapData]) -> u64 {
     found
 }
 
-pub fn process(input: &str) -> anyhow::Result<String> {
-    let (_, almanac) = almanac_parser(input).unwrap();
-    let result: u64 = almanac
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 5;
+    const PART: u8 = 1;
+    type Input<'a> = Almanac;
+    type Answer = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse_all(input, almanac_parser)?)
+    }
+
+    fn solve(almanac: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(lowest_location(&almanac))
+    }
+}
+
+fn lowest_location(almanac: &Almanac) -> u64 {
+    almanac
         .seeds
         .iter()
         .map(|seed| mapping(*seed, &almanac.seed_to_soil))
@@ -25,8 +42,7 @@ pub fn process(input: &str) -> anyhow::Result<String> {
         .map(|temperature| mapping(temperature, &almanac.temperature_to_humidity))
         .map(|humidity| mapping(humidity, &almanac.humidity_to_location))
         .min()
-        .expect("slice should not be empty");
-    Ok(result.to_string())
+        .expect("slice should not be empty")
 }
 
 #[cfg(test)]
@@ -68,7 +84,7 @@ temperature-to-humidity map:
 humidity-to-location map:
 60 56 37
 56 93 4";
-        assert_eq!("35", process(input)?);
+        assert_eq!(35, Part1::process(input)?);
         Ok(())
     }
 }
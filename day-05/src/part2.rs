@@ -1,11 +1,32 @@
 use super::{my_parser::*, part1::mapping};
+use aoc_core::{parse::parse_all, Solution};
+use tracing::debug;
 
-pub fn process(input: &str) -> anyhow::Result<String> {
-    let (_, almanac) = almanac_parser(input).unwrap();
-    let result = almanac
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 5;
+    const PART: u8 = 2;
+    type Input<'a> = Almanac;
+    type Answer = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse_all(input, almanac_parser)?)
+    }
+
+    fn solve(almanac: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(lowest_location(&almanac))
+    }
+}
+
+fn lowest_location(almanac: &Almanac) -> u64 {
+    almanac
         .seeds
         .chunks(2)
-        .flat_map(|arr| arr[0]..(arr[0] + arr[1]))
+        .flat_map(|arr| {
+            debug!(start = arr[0], len = arr[1], "seed range");
+            arr[0]..(arr[0] + arr[1])
+        })
         .map(|seed| mapping(seed, &almanac.seed_to_soil))
         .map(|soil| mapping(soil, &almanac.soil_to_fertilizer))
         .map(|fertilizer| mapping(fertilizer, &almanac.fertilizer_to_water))
@@ -14,8 +35,7 @@ pub fn process(input: &str) -> anyhow::Result<String> {
         .map(|temperature| mapping(temperature, &almanac.temperature_to_humidity))
         .map(|humidity| mapping(humidity, &almanac.humidity_to_location))
         .min()
-        .expect("slice should not be empty");
-    Ok(result.to_string())
+        .expect("slice should not be empty")
 }
 
 #[cfg(test)]
@@ -57,7 +77,7 @@ temperature-to-humidity map:
 humidity-to-location map:
 60 56 37
 56 93 4";
-        assert_eq!("46", process(input)?);
+        assert_eq!(46, Part2::process(input)?);
         Ok(())
     }
 }
@@ -0,0 +1,85 @@
+//! Random race sheets
+//!
+//! `size` bounds the time of the single long race of part 2, the short races
+//! of part 1 are its digits split into chunks.
+use rand::Rng;
+
+/// Time of the part 2 race, a real one is about five times longer
+pub const DEFAULT_SIZE: usize = 10_000_000;
+
+/// Best distance reachable within `time`
+fn max_distance(time: u128) -> u128 {
+    (time / 2) * (time - time / 2)
+}
+
+/// Splits the digits of `time` into races of at least 3ms which don't start with a 0
+fn races(rng: &mut impl Rng, time: usize) -> Vec<u64> {
+    let digits = time.to_string();
+    let mut races: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for digit in digits.chars() {
+        if !current.is_empty()
+            && digit != '0'
+            && current.parse::<u64>().expect("digits") >= 3
+            && rng.gen_bool(0.5)
+        {
+            races.push(std::mem::take(&mut current));
+        }
+        current.push(digit);
+    }
+    races.push(current);
+    races
+        .into_iter()
+        .map(|race| race.parse().expect("digits"))
+        .collect()
+}
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let time = rng.gen_range(size / 2..=size).max(3);
+    let times = races(rng, time);
+    loop {
+        // a record which can be beaten, but not by much
+        let distances: Vec<u64> = times
+            .iter()
+            .map(|&time| {
+                let max = max_distance(time as u128) as u64;
+                rng.gen_range(max / 2..max)
+            })
+            .collect();
+        let distance: String = distances.iter().map(u64::to_string).collect();
+        // the long race needs a winnable record as well
+        if distance.parse::<u128>().expect("digits") < max_distance(time as u128) {
+            let width = distances.iter().map(|n| n.to_string().len()).max();
+            let width = width.expect("at least one race");
+            let format = |numbers: &[u64]| {
+                numbers
+                    .iter()
+                    .map(|n| format!("{:>width$}", n))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            return format!(
+                "Time:     {}\nDistance: {}\n",
+                format(&times),
+                format(&distances)
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+    use aoc_core::{generate::rng, Solution};
+
+    #[test]
+    fn test_generated_input() -> anyhow::Result<()> {
+        for seed in 0..10 {
+            let input = generate(&mut rng(seed), 100_000);
+            assert!(Part1::process(&input)? > 0);
+            assert!(Part2::process(&input)? > 0);
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use aoc_core::{parse::parse_all, Solution};
 use nom::{
     bytes::complete::tag,
     character::complete::{self, line_ending, multispace1},
@@ -5,6 +6,7 @@ use nom::{
     sequence::{preceded, tuple},
     IResult,
 };
+use tracing::trace;
 
 #[derive(Debug)]
 pub struct Race {
@@ -38,20 +40,35 @@ impl Race {
     }
 }
 
-pub fn process(input: &str) -> anyhow::Result<String> {
-    let (_, races) = my_parser(input).unwrap();
-    let result: usize = races
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 6;
+    const PART: u8 = 1;
+    type Input<'a> = Vec<Race>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse_all(input, my_parser)?)
+    }
+
+    fn solve(races: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(margin_of_error(&races))
+    }
+}
+
+fn margin_of_error(races: &[Race]) -> usize {
+    races
         .iter()
         .map(|race| {
             let alternatives = race.alternatives();
             alternatives
                 .iter()
                 .filter(|&alternative| *alternative > race.distance)
-                // .inspect(|f| println!("race:{:?}, alt: {:?}", race, f))
+                .inspect(|alternative| trace!(?race, alternative, "beats the record"))
                 .count()
         })
-        .product();
-    Ok(result.to_string())
+        .product()
 }
 
 #[cfg(test)]
@@ -62,7 +79,7 @@ mod tests {
     fn test_process() -> anyhow::Result<()> {
         let input = "Time:      7  15   30
 Distance:  9  40  200";
-        assert_eq!("288", process(input)?);
+        assert_eq!(288, Part1::process(input)?);
         Ok(())
     }
 }
@@ -1,3 +1,6 @@
+use aoc_core::{ParseError, Solution};
+use tracing::trace;
+
 #[derive(Debug)]
 pub struct Race {
     pub time: usize,
@@ -10,43 +13,118 @@ impl Race {
             .map(|hold| (self.time - hold) * hold)
             .collect::<Vec<usize>>()
     }
+
+    /// Number of button hold times beating the record, by trying all of them
+    pub fn ways_to_win(&self) -> usize {
+        self.alternatives()
+            .iter()
+            .filter(|&alternative| *alternative > self.distance)
+            .inspect(|alternative| trace!(race = ?self, alternative, "beats the record"))
+            .count()
+    }
+
+    /// Same as [`Race::ways_to_win`] without trying every hold time
+    ///
+    /// Holding `h` wins if `h * (time - h) > distance`. The winning holds lie
+    /// between the roots of `h² - time * h + distance`, the integer square
+    /// root only gets us close so both ends are nudged into place.
+    pub fn ways_to_win_closed_form(&self) -> usize {
+        let (time, distance) = (self.time as u128, self.distance as u128);
+        let wins = |hold: u128| hold * (time - hold) > distance;
+        if time * time <= 4 * distance {
+            // even holding for half the time isn't enough
+            return 0;
+        }
+        let root = (time * time - 4 * distance).isqrt();
+        let mut low = (time - root) / 2;
+        while low < time && !wins(low) {
+            low += 1;
+        }
+        if low == time {
+            // e.g. a race of 1ms leaves no time to move
+            return 0;
+        }
+        while low > 1 && wins(low - 1) {
+            low -= 1;
+        }
+        // the winning holds are symmetric around time / 2
+        (time - 2 * low + 1) as usize
+    }
 }
 
-fn my_parser(input: &str) -> Race {
+fn my_parser(input: &str) -> Result<Race, ParseError> {
     let r: Vec<usize> = input
         .lines()
-        .map(|x| {
+        .enumerate()
+        .map(|(line_idx, x)| {
             let digit = x.chars().filter(|x| x.is_ascii_digit()).collect::<String>();
-            digit.parse::<usize>().expect("Ascii Digits")
+            digit.parse::<usize>().map_err(|e| {
+                ParseError::in_line(x, line_idx, 0, format!("expected a number: {}", e))
+            })
         })
-        .collect();
-    assert!(r.len() == 2, "wrong parsing");
-    Race {
+        .collect::<Result<_, _>>()?;
+    if r.len() != 2 {
+        return Err(ParseError::new(format!(
+            "expected a time and a distance line, found {} lines",
+            r.len()
+        )));
+    }
+    Ok(Race {
         time: r[0],
         distance: r[1],
-    }
+    })
 }
 
-pub fn process(input: &str) -> anyhow::Result<String> {
-    let race = my_parser(input);
-    let alternatives = race.alternatives();
-    let result: usize = alternatives
-        .iter()
-        .filter(|&alternative| *alternative > race.distance)
-        // .inspect(|f| println!("race:{:?}, alt: {:?}", race, f))
-        .count();
-    Ok(result.to_string())
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 6;
+    const PART: u8 = 2;
+    type Input<'a> = Race;
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(my_parser(input)?)
+    }
+
+    fn solve(race: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(race.ways_to_win())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_process() -> anyhow::Result<()> {
         let input = "Time:      7  15   30
 Distance:  9  40  200";
-        assert_eq!("71503", process(input)?);
+        assert_eq!(71503, Part2::process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_closed_form_example() {
+        let race = Race {
+            time: 71530,
+            distance: 940200,
+        };
+        assert_eq!(71503, race.ways_to_win_closed_form());
+    }
+
+    /// Races with a record somewhere between 0 and just above the best possible distance
+    fn race() -> impl Strategy<Value = Race> {
+        (0usize..2000).prop_flat_map(|time| {
+            (0..=time * time / 4 + 1).prop_map(move |distance| Race { time, distance })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_closed_form_matches_brute_force(race in race()) {
+            prop_assert_eq!(race.ways_to_win(), race.ways_to_win_closed_form());
+        }
+    }
 }
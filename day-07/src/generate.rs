@@ -0,0 +1,56 @@
+//! Random camel card hands
+//!
+//! `size` is the number of hands, capped at [`MAX_SIZE`].
+use rand::{seq::SliceRandom, Rng};
+use std::collections::HashSet;
+
+/// Hands of a real input
+pub const DEFAULT_SIZE: usize = 1000;
+/// More hands overflow the `u32` winnings
+pub const MAX_SIZE: usize = 2000;
+
+const CARDS: [char; 13] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+
+fn hand(rng: &mut impl Rng) -> String {
+    // pick a few distinct cards first so pairs and full houses show up as often as in the real input
+    let distinct = rng.gen_range(1..=5);
+    let cards: Vec<char> = CARDS.choose_multiple(rng, distinct).copied().collect();
+    let mut hand = cards.clone();
+    while hand.len() < 5 {
+        hand.push(*cards.choose(rng).expect("not empty"));
+    }
+    hand.shuffle(rng);
+    hand.into_iter().collect()
+}
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.min(MAX_SIZE);
+    // hands are unique so the ranking is unambiguous
+    let mut seen = HashSet::new();
+    let mut input = String::new();
+    while seen.len() < size {
+        let hand = hand(rng);
+        if seen.insert(hand.clone()) {
+            input.push_str(&format!("{} {}\n", hand, rng.gen_range(1..=1000)));
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+    use aoc_core::{generate::rng, Solution};
+
+    #[test]
+    fn test_generated_input() -> anyhow::Result<()> {
+        let input = generate(&mut rng(1), 50);
+        assert_eq!(Part1::parse(&input)?.len(), 50);
+        Part1::process(&input)?;
+        Part2::process(&input)?;
+        Ok(())
+    }
+}
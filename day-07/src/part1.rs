@@ -1,3 +1,4 @@
+use aoc_core::{parse::parse_all, Solution};
 use nom::{
     branch::alt,
     character::complete::{self, line_ending, space1},
@@ -161,15 +162,25 @@ pub fn line_parser(input: &str) -> IResult<&str, Game> {
     Ok((input, Game { hand, bet }))
 }
 
-pub fn process(input: &str) -> anyhow::Result<String> {
-    let (_, mut games): (_, Vec<Game>) = separated_list1(line_ending, line_parser)(input).unwrap();
-    games.sort_by(|a, b| a.hand.cmp(&b.hand));
-    let result: u32 = games
-        .into_iter()
-        .enumerate()
-        .fold(0, |acc, (idx, game)| acc + (idx + 1) as u32 * game.bet);
+pub struct Part1;
 
-    Ok(result.to_string())
+impl Solution for Part1 {
+    const DAY: u8 = 7;
+    const PART: u8 = 1;
+    type Input<'a> = Vec<Game>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse_all(input, separated_list1(line_ending, line_parser))?)
+    }
+
+    fn solve(mut games: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        games.sort_by(|a, b| a.hand.cmp(&b.hand));
+        Ok(games
+            .into_iter()
+            .enumerate()
+            .fold(0, |acc, (idx, game)| acc + (idx + 1) as u32 * game.bet))
+    }
 }
 
 #[cfg(test)]
@@ -205,7 +216,28 @@ T55J5 684
 KK677 28
 KTJJT 220
 QQQJA 483";
-        assert_eq!("6440", process(input)?);
+        assert_eq!(6440, Part1::process(input)?);
         Ok(())
     }
+
+    /// Parsing may fail but must neither panic nor hang, also when rendering the error
+    fn fuzz_parse(input: &str) {
+        if let Err(error) = parse_all(input, separated_list1(line_ending, line_parser)) {
+            error.to_string();
+        }
+    }
+
+    #[test]
+    fn test_fuzz_bytes() {
+        bolero::check!().for_each(|bytes: &[u8]| fuzz_parse(&String::from_utf8_lossy(bytes)));
+    }
+
+    #[test]
+    fn test_fuzz_generated() {
+        use crate::generate::generate;
+        use aoc_core::generate::{mutate, rng};
+        bolero::check!()
+            .with_type::<(u64, Vec<(u16, u8)>)>()
+            .for_each(|(seed, edits)| fuzz_parse(&mutate(&generate(&mut rng(*seed), 5), edits)));
+    }
 }
@@ -1,3 +1,4 @@
+use aoc_core::{parse::parse_all, Solution};
 use nom::{
     branch::alt,
     character::complete::{self, line_ending, space1},
@@ -9,9 +10,7 @@ use nom::{
 use std::{cmp::Ordering, collections::BTreeMap};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
-use tracing::info;
-use tracing_subscriber::fmt;
-use tracing_subscriber::fmt::format::FmtSpan;
+use tracing::{debug, info_span};
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub enum HandType {
@@ -45,7 +44,7 @@ fn is_full_house(card_map: BTreeMap<Card, u32>) -> bool {
     card_map.len() == 2 && card_map.values().cloned().any(|val| val == 3)
 }
 
-pub fn get_highest_hand(hands: &Vec<Hand>) -> Hand {
+pub fn get_highest_hand(hands: &[Hand]) -> Hand {
     assert!(!hands.is_empty(), "empty hand vector");
     *hands
         .iter()
@@ -203,26 +202,33 @@ pub fn line_parser(input: &str) -> IResult<&str, Game> {
     Ok((input, Game { hand, bet }))
 }
 
-pub fn process(input: &str) -> anyhow::Result<String> {
-    let (_, mut games): (_, Vec<Game>) = separated_list1(line_ending, line_parser)(input).unwrap();
-    // println!("{:?}", games);
-    fmt::fmt()
-        .with_span_events(FmtSpan::CLOSE)
-        .with_target(false)
-        .with_level(false)
-        .init();
-    info!("start mutations");
-    games.iter_mut().for_each(|game| {
-        game.hand.hand_type =
-            get_highest_hand(&permutate_hand(&game.hand, &mut BTreeMap::new())).hand_type;
-    });
-    info!("done mutations");
-    games.sort_by(|a, b| a.hand.cmp(&b.hand));
-    let result: u32 = games
-        .into_iter()
-        .enumerate()
-        .fold(0, |acc, (idx, game)| acc + (idx + 1) as u32 * game.bet);
-    Ok(result.to_string())
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 7;
+    const PART: u8 = 2;
+    type Input<'a> = Vec<Game>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        let games = parse_all(input, separated_list1(line_ending, line_parser))?;
+        debug!(games = games.len(), "parsed");
+        Ok(games)
+    }
+
+    fn solve(mut games: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        info_span!("mutations").in_scope(|| {
+            games.iter_mut().for_each(|game| {
+                game.hand.hand_type =
+                    get_highest_hand(&permutate_hand(&game.hand, &mut BTreeMap::new())).hand_type;
+            })
+        });
+        games.sort_by_key(|game| game.hand);
+        Ok(games
+            .into_iter()
+            .enumerate()
+            .fold(0, |acc, (idx, game)| acc + (idx + 1) as u32 * game.bet))
+    }
 }
 
 #[cfg(test)]
@@ -258,15 +264,34 @@ T55J5 684
 KK677 28
 KTJJT 220
 QQQJA 483";
-        assert_eq!("5905", process(input)?);
+        assert_eq!(5905, Part2::process(input)?);
         Ok(())
     }
 
     #[test]
     #[ignore = "sloooooooow"]
     fn test_complete_input() -> anyhow::Result<()> {
-        let input = include_str!("../input.txt");
-        assert_eq!("250384185", process(input)?);
-        Ok(())
+        aoc_core::answers::check::<Part2>()
+    }
+
+    /// Parsing may fail but must neither panic nor hang, also when rendering the error
+    fn fuzz_parse(input: &str) {
+        if let Err(error) = parse_all(input, separated_list1(line_ending, line_parser)) {
+            error.to_string();
+        }
+    }
+
+    #[test]
+    fn test_fuzz_bytes() {
+        bolero::check!().for_each(|bytes: &[u8]| fuzz_parse(&String::from_utf8_lossy(bytes)));
+    }
+
+    #[test]
+    fn test_fuzz_generated() {
+        use crate::generate::generate;
+        use aoc_core::generate::{mutate, rng};
+        bolero::check!()
+            .with_type::<(u64, Vec<(u16, u8)>)>()
+            .for_each(|(seed, edits)| fuzz_parse(&mutate(&generate(&mut rng(*seed), 5), edits)));
     }
 }
@@ -0,0 +1,111 @@
+//! Random desert maps
+//!
+//! `size` is roughly the number of nodes, capped at [`MAX_SIZE`] since node
+//! names only have three letters.
+//!
+//! Like the real input every ghost walks a loop from its `..A` node which
+//! passes its `..Z` node exactly once, after a prime multiple of the
+//! instruction length. That makes the least common multiple of the first
+//! `..Z` hits the answer of part 2. `AAA` and its loop end in `ZZZ`.
+//! Branches not taken by the instructions point to random nodes.
+use rand::{seq::SliceRandom, Rng};
+use std::collections::HashSet;
+
+/// Nodes of a real input
+pub const DEFAULT_SIZE: usize = 750;
+/// Names which don't end in `A` or `Z` run out above this
+pub const MAX_SIZE: usize = 16_000;
+
+const PRIMES: [usize; 18] = [
+    3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67,
+];
+
+/// Hands out unique random names ending in one of `last`
+struct Names(HashSet<String>);
+
+impl Names {
+    fn next(&mut self, rng: &mut impl Rng, last: &[u8]) -> String {
+        loop {
+            let name = String::from_utf8(vec![
+                rng.gen_range(b'A'..=b'Z'),
+                rng.gen_range(b'A'..=b'Z'),
+                *last.choose(rng).expect("not empty"),
+            ])
+            .expect("ascii");
+            if self.0.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.clamp(1, MAX_SIZE);
+    let inner: Vec<u8> = (b'B'..=b'Y').collect();
+    let ghosts = (size / 100).clamp(1, 6);
+    let primes: Vec<usize> = PRIMES.choose_multiple(rng, ghosts).copied().collect();
+    let instructions: Vec<bool> = (0..(size / primes.iter().sum::<usize>()).max(1))
+        .map(|_| rng.gen())
+        .collect();
+
+    let mut names = Names(HashSet::from(["AAA".to_string(), "ZZZ".to_string()]));
+    // every loop as (start, nodes), the first node of a loop is its `..Z` node
+    let loops: Vec<(String, Vec<String>)> = primes
+        .iter()
+        .enumerate()
+        .map(|(ghost, prime)| {
+            let (start, end) = if ghost == 0 {
+                ("AAA".to_string(), "ZZZ".to_string())
+            } else {
+                (names.next(rng, b"A"), names.next(rng, b"Z"))
+            };
+            let mut nodes = vec![end];
+            while nodes.len() < prime * instructions.len() {
+                nodes.push(names.next(rng, &inner));
+            }
+            (start, nodes)
+        })
+        .collect();
+    let all: Vec<&String> = loops.iter().flat_map(|(_, nodes)| nodes).collect();
+
+    let mut lines = Vec::new();
+    for (start, nodes) in loops.iter() {
+        for (step, node) in nodes.iter().enumerate() {
+            let next = &nodes[(step + 1) % nodes.len()];
+            let other = *all.choose(rng).expect("not empty");
+            let (left, right) = if instructions[step % instructions.len()] {
+                (other, next)
+            } else {
+                (next, other)
+            };
+            lines.push(format!("{} = ({}, {})", node, left, right));
+            if step == 0 {
+                // the start leaves like the `..Z` node but is never entered again
+                lines.push(format!("{} = ({}, {})", start, left, right));
+            }
+        }
+    }
+    lines.shuffle(rng);
+
+    let instructions: String = instructions
+        .into_iter()
+        .map(|right| if right { 'R' } else { 'L' })
+        .collect();
+    format!("{}\n\n{}\n", instructions, lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+    use aoc_core::{generate::rng, Solution};
+
+    #[test]
+    fn test_generated_input() -> anyhow::Result<()> {
+        let input = generate(&mut rng(1), DEFAULT_SIZE);
+        let instructions = input.lines().next().expect("instructions").len();
+        assert!(Part1::process(&input)?.is_multiple_of(instructions));
+        assert!(Part2::process(&input)?.is_multiple_of(instructions));
+        Ok(())
+    }
+}
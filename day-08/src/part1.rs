@@ -1,16 +1,45 @@
 use super::shared::*;
-use std::collections::BTreeMap;
+use aoc_core::{ParseError, Solution};
+use std::collections::{BTreeMap, HashSet};
 
-pub fn process(input: &str) -> anyhow::Result<String> {
-    let (_, (mut moves, nodes)) = my_parser(input).unwrap();
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 8;
+    const PART: u8 = 1;
+    type Input<'a> = (MoveSet, Vec<Node>);
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        let (moves, nodes) = parse(input)?;
+        if !nodes.iter().any(|node| node.name == "AAA") {
+            return Err(ParseError::new("no node `AAA` to start from").into());
+        }
+        Ok((moves, nodes))
+    }
+
+    fn solve((moves, nodes): Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        steps(moves, nodes)
+    }
+}
+
+fn steps(mut moves: MoveSet, nodes: Vec<Node>) -> anyhow::Result<usize> {
     let mut map: BTreeMap<String, Node> = BTreeMap::new();
     for node in nodes.into_iter() {
         map.entry(node.name.clone()).or_insert(node);
     }
+    // the walk is deterministic, coming back to a state means it never ends
+    let mut seen: HashSet<(&str, usize)> = HashSet::new();
     let mut number_of_moves: usize = 0;
-    let mut current_node_name: &String = &"AAA".to_string();
+    let mut current_node_name: &str = "AAA";
     while current_node_name != "ZZZ" {
-        let current_node = map.get(current_node_name).expect("well defined input");
+        if !seen.insert((current_node_name, moves.position())) {
+            anyhow::bail!(
+                "never reaches `ZZZ`, runs in circles after {} steps",
+                number_of_moves
+            );
+        }
+        let current_node = &map[current_node_name];
         let movement = moves.next().expect("should be infinite");
         match movement {
             Movement::Left => {
@@ -22,7 +51,7 @@ pub fn process(input: &str) -> anyhow::Result<String> {
         };
         number_of_moves += 1
     }
-    Ok(number_of_moves.to_string())
+    Ok(number_of_moves)
 }
 
 #[cfg(test)]
@@ -46,7 +75,7 @@ mod tests {
 AAA = (BBB, BBB)
 BBB = (AAA, ZZZ)
 ZZZ = (ZZZ, ZZZ)";
-        assert_eq!("6", process(input)?);
+        assert_eq!(6, Part1::process(input)?);
         Ok(())
     }
 
@@ -61,7 +90,40 @@ DDD = (DDD, DDD)
 EEE = (EEE, EEE)
 GGG = (GGG, GGG)
 ZZZ = (ZZZ, ZZZ)";
-        assert_eq!("2", process(input)?);
+        assert_eq!(2, Part1::process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_unknown_node() {
+        let input = "LR
+
+AAA = (BBB, ZZZ)
+BBB = (CCC, BBB)
+ZZZ = (ZZZ, ZZZ)";
+        let error = Part1::parse(input).unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!("unknown node `CCC`", error.message);
+        let location = error.location.as_ref().unwrap();
+        assert_eq!((4, 8), (location.line, location.column));
+    }
+
+    #[test]
+    fn test_no_start() {
+        let input = "L
+
+BBB = (ZZZ, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        assert!(Part1::process(input).is_err());
+    }
+
+    #[test]
+    fn test_never_ends() {
+        let input = "LR
+
+AAA = (BBB, BBB)
+BBB = (AAA, AAA)
+ZZZ = (ZZZ, ZZZ)";
+        assert!(Part1::process(input).is_err());
+    }
 }
@@ -1,49 +1,67 @@
 use super::shared::*;
-use std::collections::BTreeMap;
+use aoc_core::Solution;
+use std::collections::{BTreeMap, HashSet};
+use tracing::debug;
 
-pub fn process(input: &str) -> anyhow::Result<String> {
-    let (_, (mut moves, nodes)) = my_parser(input).unwrap();
+pub struct Part2;
 
+impl Solution for Part2 {
+    const DAY: u8 = 8;
+    const PART: u8 = 2;
+    type Input<'a> = (MoveSet, Vec<Node>);
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn solve((moves, nodes): Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        ghost_steps(moves, nodes)
+    }
+}
+
+fn ghost_steps(moves: MoveSet, nodes: Vec<Node>) -> anyhow::Result<usize> {
     let mut map: BTreeMap<String, Node> = BTreeMap::new();
     for node in nodes.clone().into_iter() {
         map.entry(node.name.clone()).or_insert(node);
     }
 
     let end_node_count = nodes.iter().filter(|node| node.name.ends_with('Z')).count();
-    let mut current_nodes: Vec<Node> = nodes
-        .into_iter()
+    let start_nodes: Vec<&Node> = map
+        .values()
         .filter(|node| node.name.ends_with('A'))
         .collect();
-    let start_node_count = current_nodes.len();
+    let start_node_count = start_nodes.len();
 
     // sanity checking since I was playing around with the input
     assert_eq!(start_node_count, end_node_count);
 
     let mut cycle_end: Vec<usize> = Vec::new();
-    let mut number_of_moves: usize = 0;
-    while !current_nodes.is_empty() {
-        let mut tmp: Vec<Node> = Vec::new();
-        let movement = moves.next().expect("should be infinite");
-        for node in current_nodes.iter() {
-            let next_node = match movement {
-                Movement::Left => map.get(&node.left),
-                Movement::Right => map.get(&node.right),
-            }
-            .expect("well defined input");
-
-            // this only works because the input is defined the way
-            // that the circle begins with the first occurrence of an node with a Z at the end
-            if next_node.name.ends_with('Z') {
-                cycle_end.push(number_of_moves + 1);
-            } else {
-                tmp.push(next_node.clone());
+    for start in start_nodes {
+        let mut moves = moves.clone();
+        // the walk is deterministic, coming back to a state means it never ends
+        let mut seen: HashSet<(&str, usize)> = HashSet::new();
+        let mut number_of_moves: usize = 0;
+        let mut node = start;
+        // this only works because the input is defined the way
+        // that the circle begins with the first occurrence of an node with a Z at the end
+        while !node.name.ends_with('Z') {
+            if !seen.insert((&node.name, moves.position())) {
+                anyhow::bail!(
+                    "the ghost starting at `{}` never reaches a node ending in `Z`",
+                    start.name
+                );
             }
+            node = match moves.next().expect("should be infinite") {
+                Movement::Left => &map[&node.left],
+                Movement::Right => &map[&node.right],
+            };
+            number_of_moves += 1;
         }
-        number_of_moves += 1;
-        current_nodes = tmp;
+        debug!(node = node.name, steps = number_of_moves, "ghost arrived");
+        cycle_end.push(number_of_moves);
     }
-    let number_of_moves = vec_lcm(cycle_end);
-    Ok(number_of_moves.to_string())
+    Ok(vec_lcm(cycle_end))
 }
 
 fn vec_lcm(input: Vec<usize>) -> usize {
@@ -82,7 +100,17 @@ mod tests {
 22Z = (22B, 22B)
 XXX = (XXX, XXX)
 ";
-        assert_eq!("6", process(input)?);
+        assert_eq!(6, Part2::process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_never_ends() {
+        let input = "L
+
+11A = (11B, 11B)
+11B = (11A, 11A)
+11Z = (11Z, 11Z)";
+        assert!(Part2::process(input).is_err());
+    }
 }
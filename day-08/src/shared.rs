@@ -1,3 +1,4 @@
+use aoc_core::{parse::parse_all, ParseError};
 use nom::{
     branch::alt,
     bytes::complete::{tag, take},
@@ -7,6 +8,7 @@ use nom::{
     sequence::{preceded, separated_pair, terminated},
     IResult, Parser,
 };
+use std::collections::HashSet;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Movement {
@@ -31,6 +33,11 @@ impl MoveSet {
     pub fn new(moves: Vec<Movement>) -> Self {
         Self { moves, current: 0 }
     }
+
+    /// Index of the instruction `next` hands out
+    pub fn position(&self) -> usize {
+        self.current
+    }
 }
 
 impl Iterator for MoveSet {
@@ -72,3 +79,49 @@ pub fn my_parser(input: &str) -> IResult<&str, (MoveSet, Vec<Node>)> {
     )(input)?;
     Ok((input, (MoveSet::new(movements), nodes)))
 }
+
+/// Parses the input and checks that every node it refers to exists
+pub fn parse(input: &str) -> Result<(MoveSet, Vec<Node>), ParseError> {
+    let (moves, nodes) = parse_all(input, my_parser)?;
+    let names: HashSet<&str> = nodes.iter().map(|node| node.name.as_str()).collect();
+    for (idx, node) in nodes.iter().enumerate() {
+        // the nodes start after the instructions and a blank line,
+        // `AAA = (BBB, CCC)` has its references at columns 7 and 12
+        for (column, name) in [(7, &node.left), (12, &node.right)] {
+            if !names.contains(name.as_str()) {
+                return Err(ParseError::at_position(
+                    input,
+                    idx + 2,
+                    column,
+                    format!("unknown node `{}`", name),
+                ));
+            }
+        }
+    }
+    Ok((moves, nodes))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate::generate;
+    use aoc_core::generate::{mutate, rng};
+
+    /// Parsing may fail but must neither panic nor hang, also when rendering the error
+    fn parse(input: &str) {
+        if let Err(error) = super::parse(input) {
+            error.to_string();
+        }
+    }
+
+    #[test]
+    fn test_fuzz_bytes() {
+        bolero::check!().for_each(|bytes: &[u8]| parse(&String::from_utf8_lossy(bytes)));
+    }
+
+    #[test]
+    fn test_fuzz_generated() {
+        bolero::check!()
+            .with_type::<(u64, Vec<(u16, u8)>)>()
+            .for_each(|(seed, edits)| parse(&mutate(&generate(&mut rng(*seed), 5), edits)));
+    }
+}
@@ -1,9 +1,10 @@
 use anyhow::Context;
-use day_09::part2::process;
+use aoc_core::{input, Solution};
+use day_09::part2::Part2;
 
 fn main() -> anyhow::Result<()> {
-    let file = include_str!("../../input.txt");
-    let result = process(file).context("process part 2")?;
+    let file = input::from_args(Part2::DAY)?;
+    let result = Part2::process(&file).context("process part 2")?;
     println!("{}", result);
     Ok(())
 }
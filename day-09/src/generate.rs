@@ -0,0 +1,47 @@
+//! Random OASIS reports
+//!
+//! `size` is the number of histories. Every history samples a small
+//! polynomial, so the differences reach zero like in the real input.
+use rand::Rng;
+
+/// Histories of a real input
+pub const DEFAULT_SIZE: usize = 200;
+
+const VALUES: i64 = 21;
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let coefficients: Vec<i64> = (0..=rng.gen_range(0..=4))
+            .map(|_| rng.gen_range(-5..=5))
+            .collect();
+        let history: Vec<String> = (0..VALUES)
+            .map(|x| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |acc, coefficient| acc * x + coefficient)
+                    .to_string()
+            })
+            .collect();
+        input.push_str(&history.join(" "));
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+    use aoc_core::{generate::rng, Solution};
+
+    #[test]
+    fn test_generated_input() -> anyhow::Result<()> {
+        let input = generate(&mut rng(1), 100);
+        assert_eq!(Part1::parse(&input)?.len(), 100);
+        Part1::process(&input)?;
+        Part2::process(&input)?;
+        Ok(())
+    }
+}
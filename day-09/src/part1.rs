@@ -1,4 +1,5 @@
 use crate::shared::*;
+use aoc_core::Solution;
 
 fn get_next_number(previous_number: Option<i32>, current_number: i32) -> i32 {
     if let Some(previous_number) = previous_number {
@@ -19,14 +20,29 @@ fn extrapolate(sequences: Vec<Vec<i32>>) -> i32 {
     previous_number.expect("has value")
 }
 
-pub fn process(input: &str) -> anyhow::Result<String> {
-    let numbers: Vec<Vec<i32>> = input.lines().map(my_parser).collect();
-    let result: i32 = numbers
-        .iter()
-        .map(|sequence| create_sequences(sequence))
-        .map(extrapolate)
-        .sum();
-    Ok(result.to_string())
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 9;
+    const PART: u8 = 1;
+    type Input<'a> = Vec<Vec<i32>>;
+    type Answer = i32;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(input
+            .lines()
+            .enumerate()
+            .map(|(line_idx, line)| my_parser(line, line_idx))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn solve(numbers: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(numbers
+            .iter()
+            .map(|sequence| create_sequences(sequence))
+            .map(extrapolate)
+            .sum())
+    }
 }
 
 #[cfg(test)]
@@ -39,7 +55,7 @@ mod tests {
 1 3 6 10 15 21
 10 13 16 21 30 45
 ";
-        assert_eq!("114", process(input)?);
+        assert_eq!(114, Part1::process(input)?);
         Ok(())
     }
 }
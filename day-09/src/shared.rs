@@ -1,3 +1,5 @@
+use aoc_core::ParseError;
+
 pub fn create_sequences(numbers: &[i32]) -> Vec<Vec<i32>> {
     let mut sequences: Vec<Vec<i32>> = Default::default();
     sequences.push(numbers.to_vec());
@@ -12,14 +14,16 @@ pub fn create_sequences(numbers: &[i32]) -> Vec<Vec<i32>> {
     sequences
 }
 
-pub fn my_parser(line: &str) -> Vec<i32> {
+pub fn my_parser(line: &str, line_idx: usize) -> Result<Vec<i32>, ParseError> {
+    let mut column = 0;
     line.split(' ')
         .map(|number| {
-            let n = number.parse::<i32>();
-            match n {
-                Err(e) => panic!("err {} on {:?}", e, number),
-                Ok(n) => n,
-            }
+            let start = column;
+            column += number.chars().count() + 1;
+            number.parse::<i32>().map_err(|e| {
+                let message = format!("invalid number {:?}: {}", number, e);
+                ParseError::in_line(line, line_idx, start, message)
+            })
         })
-        .collect::<Vec<i32>>()
+        .collect()
 }
@@ -1,9 +1,11 @@
-use colored::control;
-use day_10::part2::process;
+use aoc_core::{input, Solution};
+use day_10::part2::Part2;
 
-fn main() {
-    control::set_virtual_terminal(true).unwrap();
-    let file = include_str!("../../input.txt");
-    let result = process(file);
+fn main() -> anyhow::Result<()> {
+    #[cfg(windows)]
+    colored::control::set_virtual_terminal(true).unwrap();
+    let file = input::from_args(Part2::DAY)?;
+    let result = Part2::process(&file)?;
     println!("{}", result);
+    Ok(())
 }
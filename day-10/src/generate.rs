@@ -0,0 +1,162 @@
+//! Random pipe mazes
+//!
+//! `size` is the width and height of the field, rounded down to a multiple of 3.
+//!
+//! The loop is the outline of a random tree of 3x3 blocks. Every block
+//! starts as a ring of 8 pipes around one ground tile, tree edges between two
+//! blocks swap two parallel pipes so both rings become one. The tiles in the
+//! middle of the blocks are the only ones enclosed by the loop. Everything
+//! outside the tree is random junk.
+use aoc_grid::{Coordinate, Direction, Grid};
+use rand::{seq::SliceRandom, Rng};
+use std::collections::HashSet;
+
+/// Width and height of a real input
+pub const DEFAULT_SIZE: usize = 140;
+
+const PIPES: [char; 6] = ['|', '-', 'L', 'J', '7', 'F'];
+
+/// Fine coordinate of the tile at `(line, column)` inside `block`
+fn tile(block: Coordinate, line: usize, column: usize) -> Coordinate {
+    Coordinate::new(3 * block.column + column, 3 * block.line + line)
+}
+
+/// Connects (or disconnects) two neighbouring tiles
+fn link(connections: &mut Grid<[bool; 4]>, a: Coordinate, b: Coordinate, linked: bool) {
+    let direction = Direction::between(a.into(), b.into()).expect("neighbouring tiles");
+    connections[a][direction as usize] = linked;
+    connections[b][direction.opposite() as usize] = linked;
+}
+
+fn symbol(connections: [bool; 4]) -> Option<char> {
+    // in order of `Direction::ALL`: north, south, east, west
+    match connections {
+        [true, true, false, false] => Some('|'),
+        [false, false, true, true] => Some('-'),
+        [true, false, true, false] => Some('L'),
+        [true, false, false, true] => Some('J'),
+        [false, true, false, true] => Some('7'),
+        [false, true, true, false] => Some('F'),
+        _ => None,
+    }
+}
+
+/// Random spanning tree over about two thirds of the `blocks`x`blocks` field
+fn tree(rng: &mut impl Rng, blocks: usize) -> (HashSet<Coordinate>, Vec<(Coordinate, Coordinate)>) {
+    let field: Grid<()> = Grid::new(blocks, blocks, ()).expect("at least one block");
+    let start = Coordinate::new(rng.gen_range(0..blocks), rng.gen_range(0..blocks));
+    let mut nodes = HashSet::from([start]);
+    let mut edges = Vec::new();
+    let mut frontier: Vec<(Coordinate, Coordinate)> = field
+        .neighbours4(start)
+        .map(|neighbour| (start, neighbour))
+        .collect();
+    while nodes.len() < (blocks * blocks * 2 / 3).max(1) && !frontier.is_empty() {
+        let (from, to) = frontier.swap_remove(rng.gen_range(0..frontier.len()));
+        if nodes.insert(to) {
+            edges.push((from, to));
+            frontier.extend(field.neighbours4(to).map(|neighbour| (to, neighbour)));
+        }
+    }
+    (nodes, edges)
+}
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let blocks = (size / 3).max(1);
+    let (nodes, edges) = tree(rng, blocks);
+
+    let mut connections =
+        Grid::new(3 * blocks, 3 * blocks, [false; 4]).expect("at least one block");
+    for &block in nodes.iter() {
+        let ring = [
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+            (2, 1),
+            (2, 0),
+            (1, 0),
+        ];
+        for (&(l1, c1), &(l2, c2)) in ring.iter().zip(ring.iter().cycle().skip(1)) {
+            link(
+                &mut connections,
+                tile(block, l1, c1),
+                tile(block, l2, c2),
+                true,
+            );
+        }
+    }
+    for (a, b) in edges {
+        // `a` is the upper or left block
+        let (a, b) = if (a.line, a.column) < (b.line, b.column) {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        let (first, second) = if a.line == b.line {
+            ([(0, 2), (1, 2)], [(0, 0), (1, 0)])
+        } else {
+            ([(2, 0), (2, 1)], [(0, 0), (0, 1)])
+        };
+        let [a1, a2] = first.map(|(line, column)| tile(a, line, column));
+        let [b1, b2] = second.map(|(line, column)| tile(b, line, column));
+        link(&mut connections, a1, a2, false);
+        link(&mut connections, b1, b2, false);
+        link(&mut connections, a1, b1, true);
+        link(&mut connections, a2, b2, true);
+    }
+
+    let mut tiles: Grid<char> = connections.map(|&connections| {
+        symbol(connections).unwrap_or_else(|| {
+            if rng.gen_bool(0.5) {
+                '.'
+            } else {
+                *PIPES.choose(rng).expect("not empty")
+            }
+        })
+    });
+
+    let loop_tiles: Vec<Coordinate> = connections
+        .indexed_iter()
+        .filter(|(_, connections)| symbol(**connections).is_some())
+        .map(|(coordinate, _)| coordinate)
+        .collect();
+    let start = *loop_tiles.choose(rng).expect("at least one block");
+    tiles[start] = 'S';
+    // junk next to the start must not look connected to it
+    for direction in Direction::ALL {
+        let Some(neighbour) = start.step(direction) else {
+            continue;
+        };
+        if tiles.contains(neighbour)
+            && !connections[start][direction as usize]
+            && connections[neighbour] == [false; 4]
+        {
+            tiles[neighbour] = '.';
+        }
+    }
+
+    let mut input = tiles.to_string();
+    input.push('\n');
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+    use aoc_core::{generate::rng, Solution};
+
+    #[test]
+    fn test_generated_input() -> anyhow::Result<()> {
+        for seed in 0..5 {
+            let input = generate(&mut rng(seed), 30);
+            // every block adds 8 pipes to the loop and encloses one tile
+            let enclosed = Part2::process(&input)?;
+            assert!(enclosed > 0);
+            assert_eq!(Part1::process(&input)?, 4 * enclosed);
+        }
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
+pub mod generate;
 pub mod my_parser;
 pub mod part1;
 pub mod part2;
 pub mod types;
+pub mod visualize;
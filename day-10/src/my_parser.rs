@@ -1,5 +1,6 @@
-use super::types::coordinates::Coordinate;
-use super::types::directions::Direction;
+use aoc_core::ParseError;
+use aoc_grid::{Coordinate, Direction};
+
 use super::types::grid::Grid;
 use super::types::pipe::Pipe;
 
@@ -19,10 +20,26 @@ pub fn get_symbol_for_s(p: &Pipe) -> &'static char {
     }
 }
 
-/// Parse Pipe Chars
-fn pipe(input: char, coord: Coordinate) -> Pipe {
+/// A single character of the input, either a pipe or ground
+#[derive(Debug, Clone, Copy)]
+struct Tile(char);
+
+impl TryFrom<char> for Tile {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        if value == '.' || SYMBOLS.contains(&value) {
+            Ok(Tile(value))
+        } else {
+            Err("expected a pipe, `S` or `.`")
+        }
+    }
+}
+
+/// Parse Pipe Chars, `None` for ground
+fn pipe(input: char, coord: Coordinate) -> Option<Pipe> {
     use Direction::*;
-    match input {
+    let pipe = match input {
         '|' => Pipe {
             position: coord,
             connections: Some([North, South]),
@@ -58,46 +75,57 @@ fn pipe(input: char, coord: Coordinate) -> Pipe {
             connections: None,
             symbol: &SYMBOLS[6],
         },
-        val => panic!("found {:?} on {:?}", val, coord),
-    }
+        _ => return None,
+    };
+    Some(pipe)
 }
 
-pub fn my_parser(input: &str) -> Grid {
-    let mut x_max: usize = 0;
-    let mut y_max: usize = 0;
-    let pipes = input
-        .lines()
-        .enumerate()
-        .flat_map(|(line_idx, line)| {
-            let current_line = line
-                .chars()
-                .enumerate()
-                .flat_map(|(colum_idx, c)| {
-                    x_max = x_max.max(colum_idx + 1);
-                    if c != '.' {
-                        let coord = Coordinate {
-                            column: colum_idx,
-                            line: line_idx,
-                        };
-                        Some(pipe(c, coord))
-                    } else {
-                        None
-                    }
-                })
-                .collect::<Vec<Pipe>>();
-            y_max = y_max.max(line_idx + 1);
-            current_line
-        })
-        .collect::<Vec<Pipe>>();
+pub fn my_parser(input: &str) -> Result<Grid, ParseError> {
+    let tiles: aoc_grid::Grid<Tile> = aoc_grid::Grid::parse(input)?;
+    let pipes = tiles.map_indexed(|coord, tile| pipe(tile.0, coord));
+    let start = pipes
+        .iter()
+        .flatten()
+        .find(|pipe| pipe.symbol == &'S')
+        .map(|pipe| pipe.position);
+    let mut grid = Grid { start, pipes };
+    let Some(start) = grid.start else {
+        return Err(ParseError::new("no start `S` found"));
+    };
+    let Some(connections) = grid.get_start_connections() else {
+        let message = "start has to connect to exactly two pipes";
+        return Err(ParseError::at_position(
+            input,
+            start.line,
+            start.column,
+            message,
+        ));
+    };
+    grid[start].as_mut().expect("node exists").connections = Some(connections);
+    Ok(grid)
+}
 
-    let mut grid = Grid::new(x_max, y_max);
-    for pipe in pipes.iter() {
-        if pipe.symbol == &'S' {
-            grid.start = Some(pipe.position);
-        }
-        grid[pipe.position] = Some(pipe.to_owned());
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_tile() {
+        let err = my_parser("S-7\n|x|\nL-J").unwrap_err();
+        let location = err.location.expect("has location");
+        assert_eq!((location.line, location.column), (2, 2));
+    }
+
+    #[test]
+    fn test_missing_start() {
+        let err = my_parser("F-7\n|.|\nL-J").unwrap_err();
+        assert_eq!(err.to_string(), "no start `S` found");
+    }
+
+    #[test]
+    fn test_dangling_start() {
+        let err = my_parser("S-7\n..|\nL-J").unwrap_err();
+        let location = err.location.expect("has location");
+        assert_eq!((location.line, location.column), (1, 1));
     }
-    let start = grid.start.expect("by now start node should exist");
-    grid[start].as_mut().expect("node exists").connections = Some(grid.get_start_connections());
-    grid
 }
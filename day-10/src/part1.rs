@@ -1,19 +1,32 @@
+use aoc_core::Solution;
 use pathfinding::prelude::dijkstra_reach;
+use tracing::trace;
 
-use crate::types::shared::GridLike;
+use crate::types::grid::Grid;
 
 use super::my_parser::my_parser;
 
-pub fn process(input: &str) -> String {
-    let grid = my_parser(input);
-    grid.pretty_print();
-    let start = grid.start.expect("start exists");
-    let start_pipe = grid[start].as_ref().expect("start exists");
-    let result: usize = dijkstra_reach(start_pipe, |x, _y| x.successors(&grid))
-        .max_by_key(|r| r.total_cost)
-        .expect("found something")
-        .total_cost;
-    result.to_string()
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 10;
+    const PART: u8 = 1;
+    type Input<'a> = Grid;
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(my_parser(input)?)
+    }
+
+    fn solve(grid: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        trace!("pipes\n{}", grid);
+        let start = grid.start.expect("start exists");
+        let start_pipe = grid[start].as_ref().expect("start exists");
+        Ok(dijkstra_reach(start_pipe, |x, _y| x.successors(&grid))
+            .max_by_key(|r| r.total_cost)
+            .expect("found something")
+            .total_cost)
+    }
 }
 
 #[cfg(test)]
@@ -21,38 +34,40 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_process_1() {
+    fn test_process_1() -> anyhow::Result<()> {
         let input: &str = ".....
 .S-7.
 .|.|.
 .L-J.
 .....";
-        assert_eq!("4", process(input));
+        assert_eq!(4, Part1::process(input)?);
+        Ok(())
     }
 
     #[test]
-    fn test_process_1_5() {
+    fn test_process_1_5() -> anyhow::Result<()> {
         let input = "-L|F7
 7S-7|
 L|7||
 -L-J|
 L|-JF";
-        assert_eq!("4", process(input));
+        assert_eq!(4, Part1::process(input)?);
+        Ok(())
     }
 
     #[test]
-    fn test_process_2() {
+    fn test_process_2() -> anyhow::Result<()> {
         let input = "..F7.
 .FJ|.
 SJ.L7
 |F--J
 LJ...";
-        assert_eq!("8", process(input));
+        assert_eq!(8, Part1::process(input)?);
+        Ok(())
     }
 
     #[test]
-    fn test_input() {
-        let input = include_str!("../input.txt");
-        assert_eq!("6923", process(input));
+    fn test_input() -> anyhow::Result<()> {
+        aoc_core::answers::check::<Part1>()
     }
 }
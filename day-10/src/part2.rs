@@ -1,14 +1,60 @@
+use aoc_core::Solution;
 use pathfinding::prelude::dijkstra_reach;
+use tracing::debug;
 
 use super::my_parser::{get_symbol_for_s, my_parser};
 use crate::types::flood::*;
+use crate::types::grid::Grid;
 use crate::types::pipe::Pipe;
-use crate::types::shared::GridLike;
 
-pub fn process(input: &str) -> String {
-    // parse grid
-    let mut grid = my_parser(input);
+pub struct Part2;
 
+impl Solution for Part2 {
+    const DAY: u8 = 10;
+    const PART: u8 = 2;
+    type Input<'a> = Grid;
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(my_parser(input)?)
+    }
+
+    fn solve(grid: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(enclosed_tiles(grid))
+    }
+}
+
+fn enclosed_tiles(mut grid: Grid) -> usize {
+    // create "flood grid" for ray tracing
+    let mut flood_grid: FloodGrid = FloodGrid::new(grid.pipes.width(), grid.pipes.height(), None)
+        .expect("same size as the parsed pipes");
+
+    // create loop and fill in flood gird
+    for (pipe, _) in trace_loop(&mut grid) {
+        flood_grid[pipe.position] = Some(Flood::Pipe { x: *pipe.symbol });
+    }
+    flood_grid.rows_mut().for_each(flood_row);
+
+    debug!(
+        "flooded\n{}",
+        flood_grid.display_with(|node| node.map_or(" ".to_string(), |f| f.to_string()))
+    );
+
+    // count number of Inside Nodes
+    flood_grid
+        .iter()
+        .filter(|node| node.expect("Value") == Flood::Inside)
+        .count()
+}
+
+/// Pipes of the loop with their distance to the start, closest first
+///
+/// Replaces the `S` in `grid` with the pipe it stands for.
+///
+/// # Panics
+///
+/// Will panic if `grid` has no start
+pub fn trace_loop(grid: &mut Grid) -> Vec<(Pipe, usize)> {
     // set start to correct pipe symbol
     let start = grid.start.expect("start exists");
     grid[start].as_mut().expect("exists").symbol =
@@ -16,55 +62,32 @@ pub fn process(input: &str) -> String {
 
     // get start for search
     let start_pipe = grid[start].as_ref().expect("start exists");
+    dijkstra_reach(start_pipe, |pipe: &Pipe, _y| pipe.successors(grid))
+        .map(|r| (r.node, r.total_cost))
+        .collect()
+}
 
-    // create "flood grid" for ray tracing
-    let mut flood_grid: FloodGrid = FloodGrid::new(grid.line_max, grid.column_max);
-
-    // create loop and fill in flood gird
-    dijkstra_reach(start_pipe, |pipe: &Pipe, _y| pipe.successors(&grid))
-        .map(|r| r.node)
-        .for_each(|pipe| {
-            flood_grid.grid[pipe.position.line][pipe.position.column] =
-                Some(Flood::Pipe { x: *pipe.symbol })
-        });
-    // ray tracing
+/// Ray tracing along one row of the flood grid, the loop has to be filled in already
+///
+/// Fills every tile which isn't part of the loop with `Inside` or `Outside`.
+pub fn flood_row(line: &mut [Option<Flood>]) {
     // start at the edge (hopefully save outside)
     let mut next = Flood::Outside;
-    for line in flood_grid.grid.iter_mut() {
-        for node in line.iter_mut() {
-            if let Some(node) = node {
-                // when encountering an uncrossable pipe going East to West flip from inside to outside
-                if let Flood::Pipe { x } = node {
-                    match x {
-                        '|' => next = next.flip(),
-                        'F' => next = next.flip(),
-                        '7' => next = next.flip(),
-                        _ => {}
-                    }
+    for node in line.iter_mut() {
+        if let Some(node) = node {
+            // when encountering an uncrossable pipe going East to West flip from inside to outside
+            if let Flood::Pipe { x } = node {
+                match x {
+                    '|' => next = next.flip(),
+                    'F' => next = next.flip(),
+                    '7' => next = next.flip(),
+                    _ => {}
                 }
-            } else {
-                *node = Some(next);
             }
+        } else {
+            *node = Some(next);
         }
-        next = Flood::Outside;
     }
-
-    // print result for sanity checking
-    flood_grid.pretty_print();
-
-    // count number of Inside Nodes
-    flood_grid
-        .grid
-        .iter()
-        .fold(0, |acc, line| {
-            acc + line.iter().fold(0, |acc, node| {
-                acc + node
-                    .as_ref()
-                    .map(|x| if x == &Flood::Inside { 1 } else { 0 })
-                    .expect("Value")
-            })
-        })
-        .to_string()
 }
 
 #[cfg(test)]
@@ -72,7 +95,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_process_1() {
+    fn test_process_1() -> anyhow::Result<()> {
         let input = "...........
 .S-------7.
 .|F-----7|.
@@ -82,11 +105,12 @@ mod tests {
 .|..|.|..|.
 .L--J.L--J.
 ...........";
-        assert_eq!("4", process(input));
+        assert_eq!(4, Part2::process(input)?);
+        Ok(())
     }
 
     #[test]
-    fn test_process_1_5() {
+    fn test_process_1_5() -> anyhow::Result<()> {
         let input = "..........
 .S------7.
 .|F----7|.
@@ -96,11 +120,12 @@ mod tests {
 .|..||..|.
 .L--JL--J.
 ..........";
-        assert_eq!("4", process(input));
+        assert_eq!(4, Part2::process(input)?);
+        Ok(())
     }
 
     #[test]
-    fn test_process_2() {
+    fn test_process_2() -> anyhow::Result<()> {
         let input = ".F----7F7F7F7F-7....
 .|F--7||||||||FJ....
 .||.FJ||||||||L7....
@@ -111,12 +136,12 @@ L--J.L7...LJS7F-7L7.
 .....|FJLJ|FJ|F7|.LJ
 ....FJL-7.||.||||...
 ....L---J.LJ.LJLJ...";
-        assert_eq!("8", process(input));
+        assert_eq!(8, Part2::process(input)?);
+        Ok(())
     }
 
     #[test]
-    fn test_input() {
-        let input = include_str!("../input.txt");
-        assert_eq!("529", process(input));
+    fn test_input() -> anyhow::Result<()> {
+        aoc_core::answers::check::<Part2>()
     }
 }
@@ -1,7 +1,7 @@
 use colored::Colorize;
 use std::fmt::{Debug, Display};
 
-use crate::types::shared::{symbol_map, GridLike};
+use crate::types::shared::symbol_map;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Flood {
@@ -57,39 +57,4 @@ impl Display for Flood {
     }
 }
 
-pub struct FloodGrid {
-    max_line: usize,
-    max_column: usize,
-    pub grid: Vec<Vec<Option<Flood>>>,
-}
-
-impl FloodGrid {
-    pub fn new(max_line: usize, max_column: usize) -> Self {
-        let grid: Vec<Vec<Option<Flood>>> = (0..max_line).map(|_| vec![None; max_column]).collect();
-        FloodGrid {
-            max_line,
-            max_column,
-            grid,
-        }
-    }
-}
-
-impl GridLike for FloodGrid {
-    type Output = Flood;
-
-    fn get_item(&self, line: usize, column: usize) -> Option<Self::Output> {
-        self.grid[line][column]
-    }
-
-    fn max_column(&self) -> usize {
-        self.max_column
-    }
-
-    fn max_line(&self) -> usize {
-        self.max_line
-    }
-
-    fn get_empty_symbol(&self) -> char {
-        ' '
-    }
-}
+pub type FloodGrid = aoc_grid::Grid<Option<Flood>>;
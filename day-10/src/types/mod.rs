@@ -1,5 +1,3 @@
-pub mod coordinates;
-pub mod directions;
 pub mod flood;
 pub mod grid;
 pub mod pipe;
@@ -1,8 +1,8 @@
 use std::fmt::{Debug, Display, Error, Formatter};
 use std::hash::{Hash, Hasher};
 
-use super::coordinates::Coordinate;
-use super::directions::Direction;
+use aoc_grid::{Coordinate, Direction};
+
 use super::grid::Grid;
 use super::shared::symbol_map;
 
@@ -60,7 +60,7 @@ impl Pipe {
         if let Some(connections) = self.connections {
             connections
                 .iter()
-                .flat_map(|conn| self.position.calculate_coordinates(*conn))
+                .flat_map(|conn| self.position.step(*conn))
                 .collect::<Vec<Coordinate>>()
         } else {
             // should not get here...
@@ -72,7 +72,7 @@ impl Pipe {
     pub fn successors(&self, grid: &Grid) -> Vec<(Pipe, usize)> {
         self.get_neighbor_coordinates()
             .iter()
-            .filter(|coord| coord.is_inside(grid))
+            .filter(|coord| grid.contains(**coord))
             .flat_map(|coord| grid[*coord].as_ref().map(|p| (*p, 1usize)))
             .collect()
     }
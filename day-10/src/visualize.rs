@@ -0,0 +1,102 @@
+//! Tracing the loop away from the start, then flooding it row by row
+use crate::my_parser::my_parser;
+use crate::part2::{flood_row, trace_loop};
+use crate::types::flood::{Flood, FloodGrid};
+use crate::types::grid::Grid;
+use crate::types::shared::symbol_map;
+use aoc_core::ParseError;
+use aoc_grid::{
+    render::{Cell, Frame, Frames, Rgb},
+    Coordinate,
+};
+
+const INSIDE: Rgb = Rgb(110, 20, 20);
+const OUTSIDE: Rgb = Rgb(15, 30, 60);
+
+/// One frame per distance from the start (part 1), then one per flooded row (part 2)
+pub fn frames(input: &str) -> Result<Frames, ParseError> {
+    let mut grid = my_parser(input)?;
+    let mut pipes = trace_loop(&mut grid).into_iter().peekable();
+    let mut flood = FloodGrid::new(grid.pipes.width(), grid.pipes.height(), None)
+        .expect("same size as the parsed pipes");
+    let mut row = 0;
+    Ok(Box::new(std::iter::from_fn(move || {
+        if let Some(&(_, distance)) = pipes.peek() {
+            let mut front = Vec::new();
+            while let Some((pipe, _)) = pipes.next_if(|(_, d)| *d == distance) {
+                flood[pipe.position] = Some(Flood::Pipe { x: *pipe.symbol });
+                front.push(pipe.position);
+            }
+            return Some(render(&grid, &flood, &front));
+        }
+        if row < flood.height() {
+            flood_row(flood.row_mut(row));
+            row += 1;
+            return Some(render(&grid, &flood, &[]));
+        }
+        None
+    })))
+}
+
+/// The flooded loop, inside and outside shaded
+pub fn picture(input: &str, _steps: usize) -> Result<Frame, ParseError> {
+    Ok(frames(input)?.last().expect("a frame per row"))
+}
+
+/// The loop in green with its newest pipes in yellow, pipes which aren't part of it in grey
+fn render(grid: &Grid, flood: &FloodGrid, front: &[Coordinate]) -> Frame {
+    let mut frame = flood.map_indexed(|position, node| match node {
+        Some(Flood::Pipe { x }) => Cell::new(symbol_map(x), Rgb::GREEN),
+        Some(Flood::Inside) => Cell::new('I', Rgb::RED).on(INSIDE),
+        Some(Flood::Outside) => Cell::default().on(OUTSIDE),
+        None => grid[position].map_or(Cell::default(), |pipe| {
+            Cell::new(symbol_map(pipe.symbol), Rgb::GREY)
+        }),
+    });
+    for &position in front {
+        frame[position].fg = Rgb::YELLOW;
+    }
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_grid::image;
+
+    #[test]
+    fn test_frames() {
+        let input = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+        let frames: Vec<Frame> = frames(input).unwrap().collect();
+        // distances 0 to 23 along the loop, then the 9 rows
+        assert_eq!(24 + 9, frames.len());
+        let last = frames.last().unwrap();
+        assert_eq!(4, last.iter().filter(|cell| cell.glyph == 'I').count());
+        assert_eq!(Cell::new('┌', Rgb::YELLOW), frames[0].row(1)[1]);
+    }
+
+    #[test]
+    fn test_picture() -> anyhow::Result<()> {
+        let input = ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+        let picture = picture(input, 0)?;
+        aoc_core::snapshot::check("snapshots/part2.svg", image::svg(&picture, 10))?;
+        aoc_core::snapshot::check("snapshots/part2.png", image::png(&picture, 4))
+    }
+}
@@ -0,0 +1,37 @@
+//! Random galaxy images
+//!
+//! `size` is the width and height of the image. Galaxies are sparse and about
+//! every tenth line and column stays empty so there is something to expand.
+use rand::Rng;
+
+/// Width and height of a real input
+pub const DEFAULT_SIZE: usize = 140;
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let empty_lines: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.1)).collect();
+    let empty_columns: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.1)).collect();
+    let mut input = String::new();
+    for empty_line in empty_lines.iter() {
+        for empty_column in empty_columns.iter() {
+            let galaxy = !empty_line && !empty_column && rng.gen_bool(0.02);
+            input.push(if galaxy { '#' } else { '.' });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+    use aoc_core::{generate::rng, Solution};
+
+    #[test]
+    fn test_generated_input() -> anyhow::Result<()> {
+        let input = generate(&mut rng(1), 50);
+        assert!(input.lines().all(|line| line.len() == 50));
+        assert!(Part1::process(&input)? <= Part2::process(&input)?);
+        Ok(())
+    }
+}
@@ -1,10 +1,11 @@
+use aoc_core::{ParseError, Solution};
 use itertools::Itertools;
 use std::collections::HashSet;
 
 #[derive(Debug, Clone, Copy)]
 pub struct Position {
-    line_idx: usize,
-    column_idx: usize,
+    pub line_idx: usize,
+    pub column_idx: usize,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -53,38 +54,54 @@ pub fn find_empty_columns(image: &[Galaxy], max: usize) -> HashSet<usize> {
     map
 }
 
-pub fn my_parser(input: &str) -> (Vec<Galaxy>, usize, usize) {
+pub fn my_parser(input: &str) -> Result<(Vec<Galaxy>, usize, usize), ParseError> {
     let mut line_max: usize = 0;
     let mut column_max: usize = 0;
-    let image = input
-        .lines()
-        .enumerate()
-        .flat_map(|(line_idx, line)| {
-            line_max = line_max.max(line_idx);
-            column_max = column_max.max(line.len() - 1);
-            line.chars()
-                .enumerate()
-                .flat_map(move |(column_idx, c)| match c {
-                    '#' => Some(Galaxy {
-                        position: Position {
-                            line_idx,
-                            column_idx,
-                        },
-                    }),
-                    '.' => None,
-                    val => panic!("error parsing {}", val),
-                })
-        })
-        .collect();
-    (image, line_max, column_max)
+    let mut image = Vec::new();
+    for (line_idx, line) in input.lines().enumerate() {
+        line_max = line_max.max(line_idx);
+        column_max = column_max.max(line.len().saturating_sub(1));
+        for (column_idx, c) in line.chars().enumerate() {
+            match c {
+                '#' => image.push(Galaxy {
+                    position: Position {
+                        line_idx,
+                        column_idx,
+                    },
+                }),
+                '.' => {}
+                val => {
+                    let message = format!("expected `#` or `.`, found {:?}", val);
+                    return Err(ParseError::in_line(line, line_idx, column_idx, message));
+                }
+            }
+        }
+    }
+    Ok((image, line_max, column_max))
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 11;
+    const PART: u8 = 1;
+    type Input<'a> = (Vec<Galaxy>, usize, usize);
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(my_parser(input)?)
+    }
+
+    fn solve((image, line_max, column_max): Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(sum_of_distances(image, line_max, column_max))
+    }
 }
 
-pub fn process(input: &str) -> String {
-    let (image, line_max, column_max) = my_parser(input);
+fn sum_of_distances(image: Vec<Galaxy>, line_max: usize, column_max: usize) -> usize {
     let empty_columns: HashSet<usize> = find_empty_columns(&image, column_max);
     let empty_lines: HashSet<usize> = find_empty_lines(&image, line_max);
 
-    let image: usize = image
+    image
         .into_iter()
         .map(|mut g| {
             g.expand(&empty_lines, &empty_columns);
@@ -92,8 +109,7 @@ pub fn process(input: &str) -> String {
         })
         .combinations(2)
         .map(|x: Vec<Galaxy>| x[0].distance_to(&x[1]))
-        .sum();
-    image.to_string()
+        .sum()
 }
 
 #[cfg(test)]
@@ -119,7 +135,7 @@ mod tests {
     }
 
     #[test]
-    fn test_process() {
+    fn test_process() -> anyhow::Result<()> {
         let input = "...#......
 .......#..
 #.........
@@ -130,12 +146,12 @@ mod tests {
 ..........
 .......#..
 #...#.....";
-        assert_eq!("374", process(input));
+        assert_eq!(374, Part1::process(input)?);
+        Ok(())
     }
 
     #[test]
-    fn test_input() {
-        let input = include_str!("../input.txt");
-        assert_eq!("9918828", process(input));
+    fn test_input() -> anyhow::Result<()> {
+        aoc_core::answers::check::<Part1>()
     }
 }
@@ -1,3 +1,4 @@
+use aoc_core::{ParseError, Solution};
 use itertools::Itertools;
 use std::collections::HashSet;
 
@@ -61,38 +62,62 @@ pub fn find_empty_columns(image: &[Galaxy], max: usize) -> HashSet<usize> {
     map
 }
 
-pub fn my_parser(input: &str) -> (Vec<Galaxy>, usize, usize) {
+pub fn my_parser(input: &str) -> Result<(Vec<Galaxy>, usize, usize), ParseError> {
     let mut line_max: usize = 0;
     let mut column_max: usize = 0;
-    let image = input
-        .lines()
-        .enumerate()
-        .flat_map(|(line_idx, line)| {
-            line_max = line_max.max(line_idx);
-            column_max = column_max.max(line.len() - 1);
-            line.chars()
-                .enumerate()
-                .flat_map(move |(column_idx, c)| match c {
-                    '#' => Some(Galaxy {
-                        position: Position {
-                            line_idx,
-                            column_idx,
-                        },
-                    }),
-                    '.' => None,
-                    val => panic!("error parsing {}", val),
-                })
-        })
-        .collect();
-    (image, line_max, column_max)
+    let mut image = Vec::new();
+    for (line_idx, line) in input.lines().enumerate() {
+        line_max = line_max.max(line_idx);
+        column_max = column_max.max(line.len().saturating_sub(1));
+        for (column_idx, c) in line.chars().enumerate() {
+            match c {
+                '#' => image.push(Galaxy {
+                    position: Position {
+                        line_idx,
+                        column_idx,
+                    },
+                }),
+                '.' => {}
+                val => {
+                    let message = format!("expected `#` or `.`, found {:?}", val);
+                    return Err(ParseError::in_line(line, line_idx, column_idx, message));
+                }
+            }
+        }
+    }
+    Ok((image, line_max, column_max))
 }
 
-pub fn process(input: &str, factor: usize) -> String {
-    let (image, line_max, column_max) = my_parser(input);
+/// Expansion factor asked for by the puzzle
+pub const FACTOR: usize = 1_000_000;
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 11;
+    const PART: u8 = 2;
+    type Input<'a> = (Vec<Galaxy>, usize, usize);
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(my_parser(input)?)
+    }
+
+    fn solve((image, line_max, column_max): Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(sum_of_distances(image, line_max, column_max, FACTOR))
+    }
+}
+
+pub fn sum_of_distances(
+    image: Vec<Galaxy>,
+    line_max: usize,
+    column_max: usize,
+    factor: usize,
+) -> usize {
     let empty_columns: HashSet<usize> = find_empty_columns(&image, column_max);
     let empty_lines: HashSet<usize> = find_empty_lines(&image, line_max);
 
-    let image: usize = image
+    image
         .into_iter()
         .map(|mut g| {
             g.expand(&empty_lines, &empty_columns, factor);
@@ -100,8 +125,7 @@ pub fn process(input: &str, factor: usize) -> String {
         })
         .combinations(2)
         .map(|x: Vec<Galaxy>| x[0].distance_to(&x[1]))
-        .sum();
-    image.to_string()
+        .sum()
 }
 
 #[cfg(test)]
@@ -127,7 +151,7 @@ mod tests {
     }
 
     #[test]
-    fn test_process_1() {
+    fn test_process_1() -> anyhow::Result<()> {
         let input = "...#......
 .......#..
 #.........
@@ -138,11 +162,13 @@ mod tests {
 ..........
 .......#..
 #...#.....";
-        assert_eq!("374", process(input, 2));
+        let (image, line_max, column_max) = Part2::parse(input)?;
+        assert_eq!(374, sum_of_distances(image, line_max, column_max, 2));
+        Ok(())
     }
 
     #[test]
-    fn test_process_10() {
+    fn test_process_10() -> anyhow::Result<()> {
         let input = "...#......
 .......#..
 #.........
@@ -153,11 +179,13 @@ mod tests {
 ..........
 .......#..
 #...#.....";
-        assert_eq!("1030", process(input, 10));
+        let (image, line_max, column_max) = Part2::parse(input)?;
+        assert_eq!(1030, sum_of_distances(image, line_max, column_max, 10));
+        Ok(())
     }
 
     #[test]
-    fn test_process_100() {
+    fn test_process_100() -> anyhow::Result<()> {
         let input = "...#......
 .......#..
 #.........
@@ -168,18 +196,27 @@ mod tests {
 ..........
 .......#..
 #...#.....";
-        assert_eq!("8410", process(input, 100));
+        let (image, line_max, column_max) = Part2::parse(input)?;
+        assert_eq!(8410, sum_of_distances(image, line_max, column_max, 100));
+        Ok(())
     }
 
     #[test]
-    fn test_input_1() {
-        let input = include_str!("../input.txt");
-        assert_eq!("9918828", process(input, 2));
+    fn test_input_1() -> anyhow::Result<()> {
+        // a factor of 2 is part 1
+        let Some(input) = aoc_core::input::optional(Part2::DAY)? else {
+            return Ok(());
+        };
+        let (image, line_max, column_max) = Part2::parse(&input)?;
+        assert_eq!(
+            crate::part1::Part1::process(&input)?,
+            sum_of_distances(image, line_max, column_max, 2)
+        );
+        Ok(())
     }
 
     #[test]
-    fn test_input_2() {
-        let input = include_str!("../input.txt");
-        assert_eq!("692506533832", process(input, 1_000_000));
+    fn test_input_2() -> anyhow::Result<()> {
+        aoc_core::answers::check::<Part2>()
     }
 }
@@ -0,0 +1,123 @@
+//! The galaxies before and after the expansion of part 1
+use crate::part1::{find_empty_columns, find_empty_lines, my_parser, Galaxy};
+use aoc_core::ParseError;
+use aoc_grid::{
+    render::{Cell, Frame, Frames, Rgb},
+    Coordinate,
+};
+use std::collections::HashSet;
+
+const EMPTY: Rgb = Rgb(20, 40, 90);
+
+/// Two frames, empty lines and columns are shaded
+pub fn frames(input: &str) -> Result<Frames, ParseError> {
+    let (image, line_max, column_max) = my_parser(input)?;
+    let (height, width) = (line_max + 1, column_max + 1);
+    let empty_lines = find_empty_lines(&image, height);
+    let empty_columns = find_empty_columns(&image, width);
+    let before = render(&image, width, height, &empty_lines, &empty_columns);
+
+    let expanded: Vec<Galaxy> = image
+        .iter()
+        .map(|galaxy| {
+            let mut galaxy = *galaxy;
+            galaxy.expand(&empty_lines, &empty_columns);
+            galaxy
+        })
+        .collect();
+    let after = render(
+        &expanded,
+        width + empty_columns.len(),
+        height + empty_lines.len(),
+        &doubled(&empty_lines),
+        &doubled(&empty_columns),
+    );
+    Ok(Box::new([before, after].into_iter()))
+}
+
+/// The galaxies after the expansion
+pub fn picture(input: &str, _steps: usize) -> Result<Frame, ParseError> {
+    Ok(frames(input)?.last().expect("two frames"))
+}
+
+/// Where the empty lines (or columns) end up after the expansion, each one is there twice
+fn doubled(empty: &HashSet<usize>) -> HashSet<usize> {
+    empty
+        .iter()
+        .flat_map(|&idx| {
+            let expanded = idx + empty.iter().filter(|&&other| other < idx).count();
+            [expanded, expanded + 1]
+        })
+        .collect()
+}
+
+fn render(
+    galaxies: &[Galaxy],
+    width: usize,
+    height: usize,
+    empty_lines: &HashSet<usize>,
+    empty_columns: &HashSet<usize>,
+) -> Frame {
+    let mut frame = Frame::new(width, height, Cell::new('.', Rgb::GREY))
+        .expect("the parsed size")
+        .map_indexed(|c, cell| {
+            if empty_lines.contains(&c.line) || empty_columns.contains(&c.column) {
+                cell.on(EMPTY)
+            } else {
+                *cell
+            }
+        });
+    for galaxy in galaxies {
+        let position = Coordinate::new(galaxy.position.column_idx, galaxy.position.line_idx);
+        frame[position] = Cell::new('#', Rgb::YELLOW);
+    }
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_grid::image;
+
+    const INPUT: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[test]
+    fn test_frames() {
+        let frames: Vec<Frame> = frames(INPUT).unwrap().collect();
+        assert_eq!(2, frames.len());
+        assert_eq!(INPUT, frames[0].display_with(|cell| cell.glyph).to_string());
+        assert_eq!(
+            "\
+....#........
+.........#...
+#............
+.............
+.............
+........#....
+.#...........
+............#
+.............
+.............
+.........#...
+#....#.......",
+            frames[1].display_with(|cell| cell.glyph).to_string()
+        );
+        assert_eq!(Some(EMPTY), frames[1][Coordinate::new(3, 0)].bg);
+        assert_eq!(Some(EMPTY), frames[1][Coordinate::new(0, 4)].bg);
+    }
+
+    #[test]
+    fn test_picture() -> anyhow::Result<()> {
+        let picture = picture(INPUT, 0)?;
+        aoc_core::snapshot::check("snapshots/part1.svg", image::svg(&picture, 10))
+    }
+}
@@ -1,7 +1,10 @@
-use day_12::part2::process;
+use anyhow::Context;
+use aoc_core::{input, Solution};
+use day_12::part2::Part2;
 
-fn main() {
-    let file = include_str!("../../input.txt");
-    let result = process(file);
+fn main() -> anyhow::Result<()> {
+    let file = input::from_args(Part2::DAY)?;
+    let result = Part2::process(&file).context("process part 2")?;
     println!("{}", result);
+    Ok(())
 }
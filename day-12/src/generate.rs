@@ -0,0 +1,58 @@
+//! Random condition records
+//!
+//! `size` is the number of rows. Every row is a random arrangement of springs
+//! with part of them hidden behind `?`, so there is at least one solution. At
+//! most [`MAX_UNKNOWN`] springs per row are hidden to keep part 1 feasible.
+use rand::Rng;
+
+/// Rows of a real input
+pub const DEFAULT_SIZE: usize = 1000;
+/// Part 1 tries every combination of unknown springs
+pub const MAX_UNKNOWN: usize = 14;
+
+fn row(rng: &mut impl Rng) -> String {
+    let len = rng.gen_range(3..=20);
+    let mut springs: Vec<char> = (0..len)
+        .map(|_| if rng.gen_bool(0.5) { '#' } else { '.' })
+        .collect();
+    if !springs.contains(&'#') {
+        // rows without any group can't be written down
+        springs[rng.gen_range(0..len)] = '#';
+    }
+    let groups: Vec<String> = springs
+        .split(|spring| *spring == '.')
+        .filter(|group| !group.is_empty())
+        .map(|group| group.len().to_string())
+        .collect();
+    let mut unknown = 0;
+    for spring in springs.iter_mut() {
+        if unknown < MAX_UNKNOWN && rng.gen_bool(0.5) {
+            *spring = '?';
+            unknown += 1;
+        }
+    }
+    format!(
+        "{} {}\n",
+        springs.into_iter().collect::<String>(),
+        groups.join(",")
+    )
+}
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size).map(|_| row(rng)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+    use aoc_core::{generate::rng, Solution};
+
+    #[test]
+    fn test_generated_input() -> anyhow::Result<()> {
+        let input = generate(&mut rng(1), 20);
+        assert!(Part1::process(&input)? >= 20);
+        assert!(Part2::process(&input)? >= 20);
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
 use std::fmt::Debug;
 
+use aoc_core::{ParseError, Solution};
 use itertools::{repeat_n, Itertools};
+use tracing::{debug, trace};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Position {
@@ -45,29 +47,50 @@ pub struct Line {
     pub configuration: Vec<usize>,
 }
 
-pub fn parse_record(input: &str, line_idx: usize) -> Vec<Record> {
+pub fn parse_record(input: &str, line_idx: usize) -> Result<Vec<Record>, ParseError> {
     input
         .chars()
         .enumerate()
-        .map(|(char_idx, c)| Record {
-            typ: c.try_into().expect("should work"),
-            position: Position {
-                line: line_idx,
-                column: char_idx,
-            },
+        .map(|(char_idx, c)| {
+            let typ = c.try_into().map_err(|e| {
+                ParseError::in_line(input, line_idx, char_idx, format!("{} {:?}", e, c))
+            })?;
+            Ok(Record {
+                typ,
+                position: Position {
+                    line: line_idx,
+                    column: char_idx,
+                },
+            })
         })
         .collect()
 }
 
-pub fn line_parser(input: &str, line_idx: usize) -> Line {
-    let parts = input.split(' ').collect::<Vec<&str>>();
-    Line {
-        records: parse_record(parts[0], line_idx),
-        configuration: parts[1]
-            .split(',')
-            .flat_map(|x| x.parse::<usize>().ok())
-            .collect(),
-    }
+/// Parses the comma separated group sizes starting at `column` of `line`
+pub fn parse_groups(line: &str, line_idx: usize, column: usize) -> Result<Vec<usize>, ParseError> {
+    let mut column = column;
+    line[column..]
+        .split(',')
+        .map(|number| {
+            let start = column;
+            column += number.len() + 1;
+            number.parse::<usize>().map_err(|e| {
+                let message = format!("invalid group size {:?}: {}", number, e);
+                ParseError::in_line(line, line_idx, start, message)
+            })
+        })
+        .collect()
+}
+
+pub fn line_parser(input: &str, line_idx: usize) -> Result<Line, ParseError> {
+    let Some((records, _)) = input.split_once(' ') else {
+        let message = "expected the springs and group sizes separated by a space";
+        return Err(ParseError::in_line(input, line_idx, input.len(), message));
+    };
+    Ok(Line {
+        records: parse_record(records, line_idx)?,
+        configuration: parse_groups(input, line_idx, records.len() + 1)?,
+    })
 }
 
 fn get_options_for_unknown(len: usize, offset: usize) -> Vec<Vec<(usize, RecordType)>> {
@@ -81,21 +104,10 @@ fn get_options_for_unknown(len: usize, offset: usize) -> Vec<Vec<(usize, RecordT
                 .collect::<Vec<(usize, RecordType)>>()
         })
         .filter(|v| v.len() == len)
-        // .inspect(|x| println!("{:?}", x))
+        .inspect(|option| trace!(?option, "option for unknowns"))
         .collect()
 }
 
-// fn pretty_print(records: &[Record]) {
-//     for record in records {
-//         match record.typ {
-//             RecordType::Broken => print!("#"),
-//             RecordType::Empty => print!("."),
-//             RecordType::Unknown => print!("?"),
-//         }
-//     }
-//     println!()
-// }
-
 fn new_line(
     old_line: &[Record],
     options_for_unknown: Vec<Vec<(usize, RecordType)>>,
@@ -141,7 +153,7 @@ pub fn filter_option(configuration: &[usize], options: &[Record]) -> bool {
     if range_count != 0 {
         ranges.push(range_count);
     }
-    // println!("conf: {:?} seen: {:?}", self.configuration, ranges);
+    trace!(?configuration, ?ranges, "checked option");
     configuration == ranges
 }
 
@@ -170,11 +182,9 @@ fn merge_options(
     } else {
         let new_options: Vec<Vec<(usize, RecordType)>> = options
             .iter()
-            // .inspect(|x| println!("{:?}", x))
             .flat_map(|unknown_option| {
                 original
                     .iter()
-                    // .inspect(|x| println!("{:?}", x))
                     .map(|original_options| {
                         let mut unknown_option = unknown_option.clone();
                         let mut original_options = original_options.clone();
@@ -183,8 +193,8 @@ fn merge_options(
                     })
                     .collect::<Vec<Vec<(usize, RecordType)>>>()
             })
-            // .inspect(|x| println!("{:?}", x))
             .collect();
+        trace!(options = new_options.len(), "merged options");
         new_options
     }
 }
@@ -216,7 +226,6 @@ impl Line {
             };
         }
         if !unknown_range.is_empty() {
-            println!("here");
             let options_for_unknown = get_options_for_unknown(
                 unknown_range.len(),
                 self.records.len() - unknown_range.len(),
@@ -224,30 +233,40 @@ impl Line {
             options = merge_options(&options, &options_for_unknown);
         }
 
+        if options.is_empty() {
+            // nothing unknown, the line is its only option
+            return usize::from(filter_option(&self.configuration, &self.records));
+        }
+
         let new_line_options: usize = new_line(&self.records, options, &self.configuration);
-        // new_line_options.iter().for_each(|o| pretty_print(o));
-        println!("{:?}", new_line_options);
+        debug!(options = new_line_options, "line done");
         new_line_options
     }
 
     pub fn calculate_options(&self) -> usize {
         self.find_options()
-        // .iter()
-        // .inspect(|x| pretty_print(x))
-        // .filter(|option| self.filter_option(option))
-        // .inspect(|x| pretty_print(x))
-        // .count()
     }
 }
 
-pub fn process(input: &str) -> String {
-    input
-        .lines()
-        .enumerate()
-        .map(|(line_idx, line)| line_parser(line, line_idx))
-        .map(|line| line.calculate_options())
-        .sum::<usize>()
-        .to_string()
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 12;
+    const PART: u8 = 1;
+    type Input<'a> = Vec<Line>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(input
+            .lines()
+            .enumerate()
+            .map(|(line_idx, line)| line_parser(line, line_idx))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn solve(lines: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(lines.iter().map(|line| line.calculate_options()).sum())
+    }
 }
 
 #[cfg(test)]
@@ -255,38 +274,40 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_options() {
+    fn test_options() -> anyhow::Result<()> {
         let input = "???.#?# 1,1,3";
-        assert_eq!("1", process(input));
+        assert_eq!(1, Part1::process(input)?);
+        Ok(())
     }
 
     #[test]
-    fn test_options_1() {
+    fn test_options_1() -> anyhow::Result<()> {
         let input = ".??..??...?##. 1,1,3";
-        assert_eq!("4", process(input));
+        assert_eq!(4, Part1::process(input)?);
+        Ok(())
     }
 
     // #[test]
     // fn test_options_2() {
     //     let input = "?????????? 1,1";
-    //     assert_eq!("45", process(input));
+    //     assert_eq!(45, Part1::process(input)?);
     // }
 
     #[test]
-    fn test_process() {
+    fn test_process() -> anyhow::Result<()> {
         let input = "???.### 1,1,3
 .??..??...?##. 1,1,3
 ?#?#?#?#?#?#?#? 1,3,1,6
 ????.#...#... 4,1,1
 ????.######..#####. 1,6,5
 ?###???????? 3,2,1";
-        assert_eq!("21", process(input));
+        assert_eq!(21, Part1::process(input)?);
+        Ok(())
     }
 
     #[test]
     // #[ignore = "slow"]
-    fn test_input() {
-        let input = include_str!("../input.txt");
-        assert_eq!("7771", process(input));
+    fn test_input() -> anyhow::Result<()> {
+        aoc_core::answers::check::<Part1>()
     }
 }
@@ -1,5 +1,7 @@
+use aoc_core::{ParseError, Solution};
 use itertools::{repeat_n, Itertools};
 use memoize::memoize;
+use tracing::{debug, trace};
 
 /// since part1 was waaaaaaaaaaay to slow to work
 ///
@@ -53,21 +55,28 @@ pub fn count(input: String, nums: Vec<usize>) -> usize {
     result
 }
 
-pub fn line_parser(input: String) -> (String, Vec<usize>) {
-    input
-        .split(' ')
-        .tuple_windows::<(_, _)>()
-        .map(|(puzzle, nums)| {
-            (
-                puzzle.to_owned(),
-                nums.split(',')
-                    .map(|n| n.parse::<usize>().expect("parseable"))
-                    .collect(),
-            )
+pub fn line_parser(input: &str, line_idx: usize) -> Result<(String, Vec<usize>), ParseError> {
+    let Some((puzzle, _)) = input.split_once(' ') else {
+        let message = "expected the springs and group sizes separated by a space";
+        return Err(ParseError::in_line(input, line_idx, input.len(), message));
+    };
+    if let Some(column) = puzzle.find(|c| !".?#".contains(c)) {
+        let message = "expected one of `.?#`";
+        return Err(ParseError::in_line(input, line_idx, column, message));
+    }
+    let mut column = puzzle.len() + 1;
+    let nums = input[column..]
+        .split(',')
+        .map(|n| {
+            let start = column;
+            column += n.len() + 1;
+            n.parse::<usize>().map_err(|e| {
+                let message = format!("invalid group size {:?}: {}", n, e);
+                ParseError::in_line(input, line_idx, start, message)
+            })
         })
-        .take(1)
-        .exactly_one()
-        .unwrap()
+        .collect::<Result<_, _>>()?;
+    Ok((puzzle.to_owned(), nums))
 }
 
 pub fn unfold(input: &str) -> String {
@@ -81,23 +90,42 @@ pub fn unfold(input: &str) -> String {
     .join(" ")
 }
 
-pub fn process(input: &str) -> String {
-    let lines: Vec<(String, Vec<usize>)> = input
-        .lines()
-        .map(unfold)
-        .map(line_parser)
-        // .inspect(|x| println!("{:?}", x))
-        .collect();
-    let mut result = 0;
-    for line in lines.iter() {
-        result += count(line.0.clone(), line.1.clone());
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 12;
+    const PART: u8 = 2;
+    type Input<'a> = Vec<(String, Vec<usize>)>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(input
+            .lines()
+            .enumerate()
+            .map(|(line_idx, line)| {
+                // check the folded line so errors point into the actual input
+                line_parser(line, line_idx)?;
+                line_parser(&unfold(line), line_idx)
+            })
+            .inspect(|line| trace!(?line, "unfolded"))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn solve(lines: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        let mut result = 0;
+        for line in lines.iter() {
+            let options = count(line.0.clone(), line.1.clone());
+            debug!(springs = %line.0, options, "line done");
+            result += options;
+        }
+        Ok(result)
     }
-    result.to_string()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
     use rstest::rstest;
 
     #[test]
@@ -113,31 +141,45 @@ mod tests {
     }
 
     #[rstest]
-    #[case("???.### 1,1,3", "1")]
-    #[case(".??..??...?##. 1,1,3", "16384")]
-    #[case("?#?#?#?#?#?#?#? 1,3,1,6", "1")]
-    #[case("????.#...#... 4,1,1", "16")]
-    #[case("????.######..#####. 1,6,5", "2500")]
-    #[case("?###???????? 3,2,1", "506250")]
-    fn test_single_line(#[case] input: String, #[case] expected: String) {
-        assert_eq!(process(&input), expected);
+    #[case("???.### 1,1,3", 1)]
+    #[case(".??..??...?##. 1,1,3", 16384)]
+    #[case("?#?#?#?#?#?#?#? 1,3,1,6", 1)]
+    #[case("????.#...#... 4,1,1", 16)]
+    #[case("????.######..#####. 1,6,5", 2500)]
+    #[case("?###???????? 3,2,1", 506250)]
+    fn test_single_line(#[case] input: String, #[case] expected: usize) -> anyhow::Result<()> {
+        assert_eq!(Part2::process(&input)?, expected);
+        Ok(())
     }
 
     #[test]
-    fn test_process() {
+    fn test_process() -> anyhow::Result<()> {
         let input = "???.### 1,1,3
 .??..??...?##. 1,1,3
 ?#?#?#?#?#?#?#? 1,3,1,6
 ????.#...#... 4,1,1
 ????.######..#####. 1,6,5
 ?###???????? 3,2,1";
-        assert_eq!("525152", process(input));
+        assert_eq!(525152, Part2::process(input)?);
+        Ok(())
     }
 
     #[test]
     #[ignore = "slowish"]
-    fn test_input() {
-        let input = include_str!("../input.txt");
-        assert_eq!("10861030975833", process(input));
+    fn test_input() -> anyhow::Result<()> {
+        aoc_core::answers::check::<Part2>()
+    }
+
+    proptest! {
+        #[test]
+        fn test_count_matches_part1(
+            springs in proptest::collection::vec(prop::sample::select(vec!['.', '?', '#']), 1..=12),
+            groups in proptest::collection::vec(1usize..=3, 1..=3),
+        ) {
+            let springs: String = springs.into_iter().collect();
+            let line = format!("{} {}", springs, groups.iter().join(","));
+            let enumerated = crate::part1::line_parser(&line, 0)?.calculate_options();
+            prop_assert_eq!(enumerated, count(springs, groups));
+        }
     }
 }
@@ -0,0 +1,134 @@
+//! Random mirror patterns
+//!
+//! `size` is the number of patterns. Like in the real input every pattern
+//! has exactly one perfect reflection and exactly one reflection which is off
+//! by a single smudge.
+//!
+//! A pattern gets built symmetric around a line between rows and a line
+//! between columns. Flipping one tile in a row which has no mirror image
+//! keeps the row reflection and leaves a smudge on the column reflection.
+//! Patterns where random tiles add another reflection are thrown away.
+use aoc_grid::{Coordinate, Grid};
+use rand::Rng;
+use std::collections::HashMap;
+
+/// Patterns of a real input
+pub const DEFAULT_SIZE: usize = 100;
+
+/// Differences between both halves when folding between `fold - 1` and `fold`
+fn differences(pattern: &Grid<char>, fold: usize) -> usize {
+    let rows: Vec<&[char]> = pattern.rows().collect();
+    rows[..fold]
+        .iter()
+        .rev()
+        .zip(&rows[fold..])
+        .map(|(above, below)| {
+            above
+                .iter()
+                .zip(below.iter())
+                .filter(|(a, b)| a != b)
+                .count()
+        })
+        .sum()
+}
+
+/// Differences of every possible fold, horizontal and vertical
+fn folds(pattern: &Grid<char>) -> Vec<usize> {
+    let transposed = pattern.transpose();
+    (1..pattern.height())
+        .map(|fold| differences(pattern, fold))
+        .chain((1..transposed.height()).map(|fold| differences(&transposed, fold)))
+        .collect()
+}
+
+/// Index of `index` folded onto the smaller side of `fold`, `None` without mirror image
+fn mirrored(index: usize, fold: usize, len: usize) -> Option<usize> {
+    let reach = fold.min(len - fold);
+    (fold - reach..fold + reach)
+        .contains(&index)
+        .then(|| index.min(2 * fold - 1 - index))
+}
+
+fn candidate(rng: &mut impl Rng) -> Grid<char> {
+    let (width, height) = (rng.gen_range(5..=17), rng.gen_range(5..=17));
+    // a row fold in the middle would leave no row for the smudge
+    let row_fold = loop {
+        let fold = rng.gen_range(1..height);
+        if 2 * fold != height {
+            break fold;
+        }
+    };
+    let column_fold = rng.gen_range(1..width);
+
+    let mut tiles = HashMap::new();
+    let mut pattern = Grid::new(width, height, '.').expect("at least 5 per side");
+    for coordinate in pattern.coordinates() {
+        let line = mirrored(coordinate.line, row_fold, height).unwrap_or(coordinate.line);
+        let column = mirrored(coordinate.column, column_fold, width).unwrap_or(coordinate.column);
+        let tile =
+            tiles
+                .entry((line, column))
+                .or_insert_with(|| if rng.gen_bool(0.5) { '#' } else { '.' });
+        pattern[coordinate] = *tile;
+    }
+
+    let lines: Vec<usize> = (0..height)
+        .filter(|&line| mirrored(line, row_fold, height).is_none())
+        .collect();
+    let columns: Vec<usize> = (0..width)
+        .filter(|&column| mirrored(column, column_fold, width).is_some())
+        .collect();
+    let smudge = Coordinate::new(
+        columns[rng.gen_range(0..columns.len())],
+        lines[rng.gen_range(0..lines.len())],
+    );
+    pattern[smudge] = if pattern[smudge] == '#' { '.' } else { '#' };
+
+    if rng.gen_bool(0.5) {
+        pattern.transpose()
+    } else {
+        pattern
+    }
+}
+
+fn pattern(rng: &mut impl Rng) -> Grid<char> {
+    loop {
+        let pattern = candidate(rng);
+        let folds = folds(&pattern);
+        let count = |differences| folds.iter().filter(|&&d| d == differences).count();
+        if count(0) == 1 && count(1) == 1 {
+            return pattern;
+        }
+    }
+}
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let patterns: Vec<String> = (0..size).map(|_| pattern(rng).to_string()).collect();
+    let mut input = patterns.join("\n\n");
+    input.push('\n');
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1::Part1, part2::Part2, shared::find_symmetry};
+    use aoc_core::{generate::rng, Solution};
+
+    #[test]
+    fn test_generated_input() -> anyhow::Result<()> {
+        let input = generate(&mut rng(1), 50);
+        let patterns = Part1::parse(&input)?;
+        assert_eq!(patterns.len(), 50);
+        for pattern in patterns.iter() {
+            for smudges in [0, 1] {
+                let rows = find_symmetry(pattern, smudges);
+                let columns = find_symmetry(&pattern.transpose(), smudges);
+                assert!((rows == 0) != (columns == 0));
+            }
+        }
+        Part1::process(&input)?;
+        Part2::process(&input)?;
+        Ok(())
+    }
+}
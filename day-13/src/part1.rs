@@ -1,4 +1,7 @@
 use super::shared::*;
+use aoc_core::{parse::parse_all, Solution};
+use aoc_grid::Grid;
+use tracing::{debug, debug_span, trace};
 
 // used solution from part2 with 0 smudges instead of this
 // fn find_symmetry(block: &[String]) -> usize {
@@ -14,20 +17,40 @@ use super::shared::*;
 //     0
 // }
 
-pub fn process(input: &str) -> String {
-    let (_, lines) = parse_input(input).unwrap();
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 13;
+    const PART: u8 = 1;
+    type Input<'a> = Vec<Grid<char>>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse_all(input, parse_input)?)
+    }
+
+    fn solve(lines: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(summarize(&lines))
+    }
+}
+
+fn summarize(lines: &[Grid<char>]) -> usize {
     let mut result: usize = 0;
-    for block in lines.iter() {
+    for (index, block) in lines.iter().enumerate() {
+        let _pattern = debug_span!("pattern", index).entered();
         // rows
-        // dbg!(block);
-        result += find_symmetry(block, 0) * 100;
+        trace!("rows\n{}", block);
+        let rows = find_symmetry(block, 0);
+        result += rows * 100;
 
         // columns
-        let new_block = rotate(block);
-        // dbg!("rotated block", &new_block);
-        result += find_symmetry(&new_block, 0);
+        let new_block = block.transpose();
+        trace!("columns\n{}", new_block);
+        let columns = find_symmetry(&new_block, 0);
+        result += columns;
+        debug!(rows, columns, "reflections");
     }
-    result.to_string()
+    result
 }
 
 #[cfg(test)]
@@ -35,7 +58,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_process() {
+    fn test_process() -> anyhow::Result<()> {
         let input = "#.##..##.
 ..#.##.#.
 ##......#
@@ -51,12 +74,12 @@ mod tests {
 #####.##.
 ..##..###
 #....#..#";
-        assert_eq!("405", process(input));
+        assert_eq!(405, Part1::process(input)?);
+        Ok(())
     }
 
     #[test]
-    fn test_input() {
-        let input = include_str!("../input.txt");
-        assert_eq!("28651", process(input));
+    fn test_input() -> anyhow::Result<()> {
+        aoc_core::answers::check::<Part1>()
     }
 }
@@ -1,19 +1,42 @@
 use super::shared::*;
+use aoc_core::{parse::parse_all, Solution};
+use aoc_grid::Grid;
+use tracing::{debug, debug_span, trace};
 
-pub fn process(input: &str) -> String {
-    let (_, lines) = parse_input(input).unwrap();
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 13;
+    const PART: u8 = 2;
+    type Input<'a> = Vec<Grid<char>>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse_all(input, parse_input)?)
+    }
+
+    fn solve(lines: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(summarize(&lines))
+    }
+}
+
+fn summarize(lines: &[Grid<char>]) -> usize {
     let mut result: usize = 0;
-    for block in lines.iter() {
+    for (index, block) in lines.iter().enumerate() {
+        let _pattern = debug_span!("pattern", index).entered();
         // rows
-        // dbg!(block);
-        result += find_symmetry(block, 1) * 100;
+        trace!("rows\n{}", block);
+        let rows = find_symmetry(block, 1);
+        result += rows * 100;
 
         // columns
-        let block = rotate(block);
-        // dbg!("rotated block", &new_block);
-        result += find_symmetry(&block, 1);
+        let block = block.transpose();
+        trace!("columns\n{}", block);
+        let columns = find_symmetry(&block, 1);
+        result += columns;
+        debug!(rows, columns, "reflections");
     }
-    result.to_string()
+    result
 }
 
 #[cfg(test)]
@@ -21,7 +44,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_process() {
+    fn test_process() -> anyhow::Result<()> {
         let input = "#.##..##.
 ..#.##.#.
 ##......#
@@ -37,12 +60,12 @@ mod tests {
 #####.##.
 ..##..###
 #....#..#";
-        assert_eq!("400", process(input));
+        assert_eq!(400, Part2::process(input)?);
+        Ok(())
     }
 
     #[test]
-    fn test_input() {
-        let input = include_str!("../input.txt");
-        assert_eq!("25450", process(input));
+    fn test_input() -> anyhow::Result<()> {
+        aoc_core::answers::check::<Part2>()
     }
 }
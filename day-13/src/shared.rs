@@ -1,52 +1,39 @@
-use std::usize;
-
+use aoc_grid::Grid;
 use nom::{
     character::complete::{line_ending, one_of},
+    combinator::{map_res, recognize},
     multi::{many1, separated_list1},
     sequence::tuple,
-    IResult, Parser,
+    IResult,
 };
 
-pub fn parse_input(input: &str) -> IResult<&str, Vec<Vec<String>>> {
+pub fn parse_input(input: &str) -> IResult<&str, Vec<Grid<char>>> {
     separated_list1(
         tuple((line_ending, line_ending)),
-        separated_list1(
-            line_ending,
-            many1(one_of("#.")).map(|x| x.iter().collect::<String>()),
+        map_res(
+            recognize(separated_list1(line_ending, many1(one_of("#.")))),
+            Grid::parse,
         ),
     )(input)
 }
 
-pub fn rotate(block: &[String]) -> Vec<String> {
-    let mut result: Vec<Vec<char>> = vec![vec!['\0'; block.len()]; block[0].len()];
-    for (line_idx, line) in block.iter().enumerate() {
-        for (char_idx, c) in line.chars().enumerate() {
-            result[char_idx][line_idx] = c;
-        }
-    }
-    let result: Vec<String> = result
-        .into_iter()
-        .map(|x| x.iter().collect::<String>())
-        .collect();
-    result
-}
-
-pub fn find_symmetry(block: &[String], number_of_smudges: usize) -> usize {
+pub fn find_symmetry<T: PartialEq>(block: &Grid<T>, number_of_smudges: usize) -> usize {
     find_symmetry_fold(block, number_of_smudges)
 }
 
-pub fn find_symmetry_map(block: &[String], number_of_smudges: usize) -> usize {
-    for x in 1..block.len() {
-        let above = &block[..x];
-        let below = &block[x..];
+pub fn find_symmetry_map<T: PartialEq>(block: &Grid<T>, number_of_smudges: usize) -> usize {
+    let rows: Vec<&[T]> = block.rows().collect();
+    for x in 1..rows.len() {
+        let above = &rows[..x];
+        let below = &rows[x..];
 
         if below
             .iter()
             .zip(above.iter().rev())
             .map(|(row_below, row_above)| {
                 row_below
-                    .chars()
-                    .zip(row_above.chars())
+                    .iter()
+                    .zip(row_above.iter())
                     .map(
                         |(below_char, above_char)| {
                             if below_char == above_char {
@@ -67,16 +54,17 @@ pub fn find_symmetry_map(block: &[String], number_of_smudges: usize) -> usize {
     0
 }
 
-pub fn find_symmetry_fold(block: &[String], number_of_smudges: usize) -> usize {
-    for x in 1..block.len() {
-        let above = &block[..x];
-        let below = &block[x..];
+pub fn find_symmetry_fold<T: PartialEq>(block: &Grid<T>, number_of_smudges: usize) -> usize {
+    let rows: Vec<&[T]> = block.rows().collect();
+    for x in 1..rows.len() {
+        let above = &rows[..x];
+        let below = &rows[x..];
 
         if below.iter().zip(above.iter().rev()).fold(
             0,
             |line_acc: usize, (below_line, above_line)| {
                 line_acc
-                    + below_line.chars().zip(above_line.chars()).fold(
+                    + below_line.iter().zip(above_line.iter()).fold(
                         0,
                         |acc: usize, (below_char, above_char)| {
                             acc + (if below_char == above_char { 0 } else { 1 })
@@ -90,3 +78,20 @@ pub fn find_symmetry_fold(block: &[String], number_of_smudges: usize) -> usize {
     }
     0
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_grid::grid_strategy;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_map_matches_fold(block in grid_strategy(any::<bool>(), 8), smudges in 0usize..=2) {
+            prop_assert_eq!(
+                find_symmetry_map(&block, smudges),
+                find_symmetry_fold(&block, smudges)
+            );
+        }
+    }
+}
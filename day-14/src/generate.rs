@@ -0,0 +1,38 @@
+//! Random reflector dishes
+//!
+//! `size` is the width and height of the dish.
+use rand::Rng;
+
+/// Width and height of a real input
+pub const DEFAULT_SIZE: usize = 100;
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            input.push(match rng.gen_range(0..100) {
+                0..=19 => 'O',
+                20..=34 => '#',
+                _ => '.',
+            });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+    use aoc_core::{generate::rng, Solution};
+
+    #[test]
+    fn test_generated_input() -> anyhow::Result<()> {
+        let input = generate(&mut rng(1), 20);
+        assert_eq!(Part1::parse(&input)?.height(), 20);
+        Part1::process(&input)?;
+        Part2::process(&input)?;
+        Ok(())
+    }
+}
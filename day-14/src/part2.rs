@@ -1,299 +1,63 @@
+use super::shared::*;
+use aoc_core::Solution;
+use aoc_grid::{Direction, Grid};
 use std::collections::HashMap;
+use tracing::debug;
 
-use nom::{
-    character::complete::{line_ending, one_of},
-    multi::{many1, separated_list1},
-    IResult, Parser,
-};
-
-pub fn parse_input(input: &str) -> IResult<&str, Vec<String>> {
-    separated_list1(
-        line_ending,
-        many1(one_of("#.O")).map(|x| x.iter().collect::<String>()),
-    )(input)
+/// One spin cycle: tilts north, west, south and east
+pub fn rotate(grid: Grid<Rock>) -> Grid<Rock> {
+    let grid = roll(Direction::North, grid);
+    let grid = roll(Direction::West, grid);
+    let grid = roll(Direction::South, grid);
+    roll(Direction::East, grid)
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-pub enum Direction {
-    North,
-    West,
-    East,
-    South,
-}
+pub struct Part2;
 
-fn roll(dir: Direction, grid: Vec<String>) -> Vec<String> {
-    use Direction::*;
-    let mut result: Vec<String> = Vec::new();
-    match dir {
-        West => {
-            for line in grid.iter() {
-                let mut tmp_line = vec!['.'; line.len()];
-                let mut next_stone_idx: usize = 0;
-                for (c_idx, c) in line.chars().enumerate() {
-                    match c {
-                        '.' => {
-                            continue;
-                        }
-                        '#' | 'N' => {
-                            tmp_line[c_idx] = c;
-                            next_stone_idx = c_idx + 1;
-                        }
-                        'O' => {
-                            tmp_line[next_stone_idx] = 'O';
-                            next_stone_idx += 1;
-                        }
-                        val => unreachable!("found {}", val),
-                    }
-                }
-                result.push(tmp_line.into_iter().collect());
-            }
-        }
-        North => {
-            let mut tmp_grid = vec![vec!['.'; grid[0].len()]; grid.len()];
-            let mut next_stone_per_col: Vec<usize> = vec![0; grid[0].len()];
-            for (line_idx, line) in grid.iter().enumerate() {
-                for (c_idx, c) in line.chars().enumerate() {
-                    match c {
-                        '.' => {
-                            continue;
-                        }
-                        '#' | 'N' => {
-                            tmp_grid[line_idx][c_idx] = c;
-                            next_stone_per_col[c_idx] = line_idx + 1;
-                        }
-                        'O' => {
-                            tmp_grid[next_stone_per_col[c_idx]][c_idx] = 'O';
-                            next_stone_per_col[c_idx] += 1;
-                        }
-                        val => unreachable!("found {}", val),
-                    }
-                }
-                result = tmp_grid
-                    .iter()
-                    .map(|line| line.iter().collect::<String>())
-                    .collect();
-            }
-        }
-        East => {
-            for line in grid.iter() {
-                let mut tmp_line = vec!['.'; line.len()];
-                let mut next_stone_idx: usize = 0;
-                for (c_idx, c) in line.chars().rev().enumerate() {
-                    match c {
-                        '.' => {
-                            continue;
-                        }
-                        '#' | 'N' => {
-                            tmp_line[c_idx] = c;
-                            next_stone_idx = c_idx + 1;
-                        }
-                        'O' => {
-                            tmp_line[next_stone_idx] = 'O';
-                            next_stone_idx += 1;
-                        }
-                        val => unreachable!("found {}", val),
-                    }
-                }
-                result.push(tmp_line.into_iter().rev().collect());
-            }
-        }
-        South => {
-            let mut tmp_grid: Vec<Vec<char>> = vec![vec!['.'; grid[0].len()]; grid.len()];
-            let mut next_stone_per_col: Vec<usize> = vec![0; grid[0].len()];
-            for (line_idx, line) in grid.iter().rev().enumerate() {
-                for (c_idx, c) in line.chars().enumerate() {
-                    match c {
-                        '.' => {
-                            continue;
-                        }
-                        '#' | 'N' => {
-                            tmp_grid[line_idx][c_idx] = c;
-                            next_stone_per_col[c_idx] = line_idx + 1;
-                        }
-                        'O' => {
-                            tmp_grid[next_stone_per_col[c_idx]][c_idx] = 'O';
-                            next_stone_per_col[c_idx] += 1;
-                        }
-                        val => unreachable!("found {}", val),
-                    }
-                }
-                result = tmp_grid
-                    .iter()
-                    .rev()
-                    .map(|line| line.iter().collect::<String>())
-                    .collect();
-            }
-        }
+impl Solution for Part2 {
+    const DAY: u8 = 14;
+    const PART: u8 = 2;
+    type Input<'a> = Grid<Rock>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
     }
-    result
-}
 
-fn count(grid: &[String]) -> usize {
-    grid.iter()
-        .rev()
-        .enumerate()
-        .fold(0, |acc, (line_idx, line)| {
-            acc + line
-                .chars()
-                .map(|c| if c == 'O' { line_idx + 1 } else { 0 })
-                .sum::<usize>()
-        })
+    fn solve(grid: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(load_after_cycles(grid))
+    }
 }
 
-fn rotate(grid: Vec<String>) -> Vec<String> {
-    let grid = roll(Direction::North, grid);
-    let grid = roll(Direction::West, grid);
-    let grid = roll(Direction::South, grid);
-    roll(Direction::East, grid)
+fn load_after_cycles(grid: Grid<Rock>) -> usize {
+    count(&after_cycles(grid, 1_000_000_000))
 }
 
-pub fn process(input: &str) -> String {
-    let (_, lines) = parse_input(input).unwrap();
-
-    let mut map: HashMap<Vec<String>, usize> = HashMap::new();
-    let mut grid = lines.clone();
-    let mut x: usize = 0;
-    while x < 1_000_000_000_usize {
-        if map.contains_key(&grid) {
-            let start_period = map.get(&grid).expect("key exists");
-            let period_len = map.len() - start_period;
-            x = (1_000_000_000 - start_period) % period_len + start_period;
-            break;
-        } else {
-            map.insert(grid.clone(), x);
-            let new_grid = rotate(grid.clone());
-            x += 1;
-            grid = new_grid;
+/// The platform after `cycles` spin cycles, skipping ahead once the states repeat
+pub fn after_cycles(grid: Grid<Rock>, cycles: usize) -> Grid<Rock> {
+    let mut map: HashMap<Grid<Rock>, usize> = HashMap::new();
+    let mut grid = grid;
+    for x in 0..cycles {
+        if let Some(&start_period) = map.get(&grid) {
+            let period_len = x - start_period;
+            debug!(start = start_period, len = period_len, "found cycle");
+            let x = (cycles - start_period) % period_len + start_period;
+            return map
+                .into_iter()
+                .find(|(_, v)| v == &x)
+                .expect("key exists")
+                .0;
         }
+        map.insert(grid.clone(), x);
+        grid = rotate(grid);
     }
-    let grid = map.into_iter().find(|(_, v)| v == &x).expect("key exists");
-    count(&grid.0).to_string()
+    grid
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_roll_west() {
-        let input = "O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....";
-        let (_, grid) = parse_input(input).unwrap();
-        assert_eq!(
-            vec![
-                "O....#....",
-                "OOO.#....#",
-                ".....##...",
-                "OO.#OO....",
-                "OO......#.",
-                "O.#O...#.#",
-                "O....#OO..",
-                "O.........",
-                "#....###..",
-                "#OO..#....",
-            ],
-            roll(Direction::West, grid)
-        );
-    }
-
-    #[test]
-    fn test_roll_east() {
-        let input = "O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....";
-        let (_, grid) = parse_input(input).unwrap();
-        assert_eq!(
-            vec![
-                "....O#....",
-                ".OOO#....#",
-                ".....##...",
-                ".OO#....OO",
-                "......OO#.",
-                ".O#...O#.#",
-                "....O#..OO",
-                ".........O",
-                "#....###..",
-                "#..OO#....",
-            ],
-            roll(Direction::East, grid)
-        );
-    }
-
-    #[test]
-    fn test_roll_north() {
-        let input = "O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....";
-        let (_, grid) = parse_input(input).unwrap();
-        assert_eq!(
-            vec![
-                "OOOO.#.O..",
-                "OO..#....#",
-                "OO..O##..O",
-                "O..#.OO...",
-                "........#.",
-                "..#....#.#",
-                "..O..#.O.O",
-                "..O.......",
-                "#....###..",
-                "#....#....",
-            ],
-            roll(Direction::North, grid)
-        );
-    }
-
-    #[test]
-    fn test_roll_south() {
-        let input = "O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....";
-        let (_, grid) = parse_input(input).unwrap();
-        assert_eq!(
-            vec![
-                ".....#....",
-                "....#....#",
-                "...O.##...",
-                "...#......",
-                "O.O....O#O",
-                "O.#..O.#.#",
-                "O....#....",
-                "OO....OO..",
-                "#OO..###..",
-                "#OO.O#...O",
-            ],
-            roll(Direction::South, grid)
-        );
-    }
-
     #[test]
     fn test_cycles() {
         let input = "O....#....
@@ -307,7 +71,7 @@ O.#..O.#.#
 #....###..
 #OO..#....";
 
-        let results = vec![
+        let results = [
             vec![
                 ".....#....",
                 "....#...O#",
@@ -346,16 +110,27 @@ O.#..O.#.#
             ],
         ];
 
-        let (_, grid) = parse_input(input).unwrap();
+        let grid = parse_input(input).unwrap();
 
-        assert_eq!(rotate(grid.clone()), results[0]);
-        assert_eq!(rotate(rotate(grid.clone())), results[1]);
-        assert_eq!(rotate(rotate(rotate(grid.clone()))), results[2]);
+        assert_eq!(rotate(grid.clone()).to_string(), results[0].join("\n"));
+        assert_eq!(
+            rotate(rotate(grid.clone())).to_string(),
+            results[1].join("\n")
+        );
+        assert_eq!(
+            rotate(rotate(rotate(grid.clone()))).to_string(),
+            results[2].join("\n")
+        );
+        assert_eq!(
+            after_cycles(grid.clone(), 3).to_string(),
+            results[2].join("\n")
+        );
     }
 
     #[test]
-    fn test_count() {
-        let input = "O....#....
+    fn test_after_cycles_skips_ahead() {
+        let grid = parse_input(
+            "O....#....
 O.OO#....#
 .....##...
 OO.#O....O
@@ -364,14 +139,16 @@ O.#..O.#.#
 ..O..#O..O
 .......O..
 #....###..
-#OO..#....";
-        let (_, grid) = parse_input(input).unwrap();
-        let grid = roll(Direction::North, grid);
-        assert_eq!(136, count(&grid));
+#OO..#....",
+        )
+        .unwrap();
+        let spun = (0..50).fold(grid.clone(), |grid, _| rotate(grid));
+        assert_eq!(spun, after_cycles(grid.clone(), 50));
+        assert_eq!(grid, after_cycles(grid.clone(), 0));
     }
 
     #[test]
-    fn test_process() {
+    fn test_process() -> anyhow::Result<()> {
         let input = "O....#....
 O.OO#....#
 .....##...
@@ -382,12 +159,12 @@ O.#..O.#.#
 .......O..
 #....###..
 #OO..#....";
-        assert_eq!("64", process(input));
+        assert_eq!(64, Part2::process(input)?);
+        Ok(())
     }
 
     #[test]
-    fn test_input() {
-        let input = include_str!("../input.txt");
-        assert_eq!("86069", process(input));
+    fn test_input() -> anyhow::Result<()> {
+        aoc_core::answers::check::<Part2>()
     }
 }
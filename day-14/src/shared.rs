@@ -0,0 +1,244 @@
+use std::fmt::Display;
+
+use aoc_core::ParseError;
+use aoc_grid::{Coordinate, Direction, Grid};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Rock {
+    Round,
+    Cube,
+    Empty,
+}
+
+impl TryFrom<char> for Rock {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'O' => Ok(Rock::Round),
+            '#' => Ok(Rock::Cube),
+            '.' => Ok(Rock::Empty),
+            _ => Err("expected one of `O#.`"),
+        }
+    }
+}
+
+impl Display for Rock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Rock::Round => 'O',
+            Rock::Cube => '#',
+            Rock::Empty => '.',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<Rock>, ParseError> {
+    Grid::parse(input)
+}
+
+/// Tilts the platform so that all round rocks roll into `dir`
+pub fn roll(dir: Direction, mut grid: Grid<Rock>) -> Grid<Rock> {
+    use Direction::*;
+    let (width, height) = (grid.width(), grid.height());
+    match dir {
+        North | South => {
+            for column in 0..width {
+                if dir == North {
+                    slide(&mut grid, height, |line| Coordinate { column, line });
+                } else {
+                    slide(&mut grid, height, |idx| Coordinate {
+                        column,
+                        line: height - 1 - idx,
+                    });
+                }
+            }
+        }
+        West | East => {
+            for line in 0..height {
+                if dir == West {
+                    slide(&mut grid, width, |column| Coordinate { column, line });
+                } else {
+                    slide(&mut grid, width, |idx| Coordinate {
+                        column: width - 1 - idx,
+                        line,
+                    });
+                }
+            }
+        }
+    }
+    grid
+}
+
+/// moves every round rock of a lane as far to the front as possible
+///
+/// `at(idx)` is the `idx`th tile of the lane counted from the front.
+fn slide(grid: &mut Grid<Rock>, len: usize, at: impl Fn(usize) -> Coordinate) {
+    let mut next_stone_idx: usize = 0;
+    for idx in 0..len {
+        let coord = at(idx);
+        match grid[coord] {
+            Rock::Empty => {}
+            Rock::Cube => next_stone_idx = idx + 1,
+            Rock::Round => {
+                grid[coord] = Rock::Empty;
+                grid[at(next_stone_idx)] = Rock::Round;
+                next_stone_idx += 1;
+            }
+        }
+    }
+}
+
+/// total load on the north support beams
+pub fn count(grid: &Grid<Rock>) -> usize {
+    grid.rows()
+        .rev()
+        .enumerate()
+        .map(|(line_idx, line)| {
+            line.iter().filter(|rock| rock == &&Rock::Round).count() * (line_idx + 1)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_grid::grid_strategy;
+    use proptest::prelude::*;
+
+    const INPUT: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    #[test]
+    fn test_roll_west() {
+        let grid = parse_input(INPUT).unwrap();
+        assert_eq!(
+            [
+                "O....#....",
+                "OOO.#....#",
+                ".....##...",
+                "OO.#OO....",
+                "OO......#.",
+                "O.#O...#.#",
+                "O....#OO..",
+                "O.........",
+                "#....###..",
+                "#OO..#....",
+            ]
+            .join("\n"),
+            roll(Direction::West, grid).to_string()
+        );
+    }
+
+    #[test]
+    fn test_roll_east() {
+        let grid = parse_input(INPUT).unwrap();
+        assert_eq!(
+            [
+                "....O#....",
+                ".OOO#....#",
+                ".....##...",
+                ".OO#....OO",
+                "......OO#.",
+                ".O#...O#.#",
+                "....O#..OO",
+                ".........O",
+                "#....###..",
+                "#..OO#....",
+            ]
+            .join("\n"),
+            roll(Direction::East, grid).to_string()
+        );
+    }
+
+    #[test]
+    fn test_roll_north() {
+        let grid = parse_input(INPUT).unwrap();
+        assert_eq!(
+            [
+                "OOOO.#.O..",
+                "OO..#....#",
+                "OO..O##..O",
+                "O..#.OO...",
+                "........#.",
+                "..#....#.#",
+                "..O..#.O.O",
+                "..O.......",
+                "#....###..",
+                "#....#....",
+            ]
+            .join("\n"),
+            roll(Direction::North, grid).to_string()
+        );
+    }
+
+    #[test]
+    fn test_roll_south() {
+        let grid = parse_input(INPUT).unwrap();
+        assert_eq!(
+            [
+                ".....#....",
+                "....#....#",
+                "...O.##...",
+                "...#......",
+                "O.O....O#O",
+                "O.#..O.#.#",
+                "O....#....",
+                "OO....OO..",
+                "#OO..###..",
+                "#OO.O#...O",
+            ]
+            .join("\n"),
+            roll(Direction::South, grid).to_string()
+        );
+    }
+
+    #[test]
+    fn test_count() {
+        let grid = parse_input(INPUT).unwrap();
+        let grid = roll(Direction::North, grid);
+        assert_eq!(136, count(&grid));
+    }
+
+    fn dish() -> impl Strategy<Value = Grid<Rock>> {
+        grid_strategy(
+            prop::sample::select(vec![Rock::Round, Rock::Cube, Rock::Empty]),
+            10,
+        )
+    }
+
+    fn direction() -> impl Strategy<Value = Direction> {
+        prop::sample::select(Direction::ALL.to_vec())
+    }
+
+    proptest! {
+        #[test]
+        fn test_roll_twice_is_roll_once(grid in dish(), dir in direction()) {
+            let once = roll(dir, grid);
+            prop_assert_eq!(roll(dir, once.clone()), once);
+        }
+
+        #[test]
+        fn test_roll_only_moves_round_rocks(grid in dish(), dir in direction()) {
+            let rolled = roll(dir, grid.clone());
+            let cubes = |grid: &Grid<Rock>| {
+                grid.indexed_iter()
+                    .filter(|(_, rock)| **rock == Rock::Cube)
+                    .map(|(coordinate, _)| coordinate)
+                    .collect::<Vec<_>>()
+            };
+            let round = |grid: &Grid<Rock>| grid.iter().filter(|rock| **rock == Rock::Round).count();
+            prop_assert_eq!(cubes(&rolled), cubes(&grid));
+            prop_assert_eq!(round(&rolled), round(&grid));
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! The round rocks rolling through the first spin cycles, one frame per tile they move
+use crate::part2::after_cycles;
+use crate::shared::{parse_input, Rock};
+use aoc_core::ParseError;
+use aoc_grid::{
+    render::{Cell, Frame, Frames, Rgb},
+    Direction, Grid,
+};
+
+/// Spin cycles shown, the first tilt to the north is part 1
+pub const CYCLES: usize = 3;
+
+pub fn frames(input: &str) -> Result<Frames, ParseError> {
+    use Direction::*;
+    let mut grid = parse_input(input)?;
+    let mut tilts = [North, West, South, East]
+        .into_iter()
+        .cycle()
+        .take(4 * CYCLES);
+    let mut tilt = tilts.next();
+    let first = render(&grid);
+    Ok(Box::new(std::iter::once(first).chain(std::iter::from_fn(
+        move || loop {
+            let dir = tilt?;
+            if nudge(&mut grid, dir) {
+                return Some(render(&grid));
+            }
+            tilt = tilts.next();
+        },
+    ))))
+}
+
+/// The rocks after `cycles` spin cycles
+pub fn picture(input: &str, cycles: usize) -> Result<Frame, ParseError> {
+    Ok(render(&after_cycles(parse_input(input)?, cycles)))
+}
+
+/// Moves every round rock which isn't blocked one tile towards `dir`
+///
+/// Rocks are moved front first, so a row of rocks rolls along together.
+/// Returns whether any rock moved.
+fn nudge(grid: &mut Grid<Rock>, dir: Direction) -> bool {
+    let coordinates: Vec<_> = match dir {
+        Direction::North | Direction::West => grid.coordinates().collect(),
+        Direction::South | Direction::East => grid.coordinates().rev().collect(),
+    };
+    let mut moved = false;
+    for from in coordinates {
+        let Some(to) = from.step(dir) else {
+            continue;
+        };
+        if grid[from] == Rock::Round && grid.get(to) == Some(&Rock::Empty) {
+            grid[from] = Rock::Empty;
+            grid[to] = Rock::Round;
+            moved = true;
+        }
+    }
+    moved
+}
+
+fn render(grid: &Grid<Rock>) -> Frame {
+    grid.map(|rock| match rock {
+        Rock::Round => Cell::new('O', Rgb::YELLOW),
+        Rock::Cube => Cell::new('#', Rgb::WHITE),
+        Rock::Empty => Cell::new('.', Rgb::GREY),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::roll;
+    use aoc_grid::image;
+
+    const INPUT: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    #[test]
+    fn test_nudge_until_rolled() {
+        for dir in Direction::ALL {
+            let mut grid = parse_input(INPUT).unwrap();
+            while nudge(&mut grid, dir) {}
+            assert_eq!(roll(dir, parse_input(INPUT).unwrap()), grid);
+        }
+    }
+
+    #[test]
+    fn test_frames() {
+        let frames: Vec<Frame> = frames(INPUT).unwrap().collect();
+        let rocks = |frame: &Frame| frame.iter().filter(|cell| cell.glyph == 'O').count();
+        assert!(frames.iter().all(|frame| rocks(frame) == 18));
+        assert!(frames.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    fn test_picture() -> anyhow::Result<()> {
+        let picture = picture(INPUT, 3)?;
+        aoc_core::snapshot::check("snapshots/cycle-3.svg", image::svg(&picture, 10))
+    }
+}
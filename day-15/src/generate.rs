@@ -0,0 +1,46 @@
+//! Random initialization sequences
+//!
+//! `size` is the number of steps. Labels are drawn from a limited pool so
+//! lenses actually get replaced and removed. The sequence is a single line
+//! without a trailing newline, part 1 hashes every byte.
+use rand::{seq::SliceRandom, Rng};
+
+/// Steps of a real input
+pub const DEFAULT_SIZE: usize = 4000;
+
+fn label(rng: &mut impl Rng) -> String {
+    (0..rng.gen_range(2..=6))
+        .map(|_| rng.gen_range('a'..='z'))
+        .collect()
+}
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let labels: Vec<String> = (0..(size / 4).max(1)).map(|_| label(rng)).collect();
+    (0..size)
+        .map(|_| {
+            let label = labels.choose(rng).expect("not empty");
+            if rng.gen_bool(0.3) {
+                format!("{}-", label)
+            } else {
+                format!("{}={}", label, rng.gen_range(1..=9))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+    use aoc_core::{generate::rng, Solution};
+
+    #[test]
+    fn test_generated_input() -> anyhow::Result<()> {
+        let input = generate(&mut rng(1), 500);
+        assert_eq!(Part2::parse(&input)?.len(), 500);
+        Part1::process(&input)?;
+        Part2::process(&input)?;
+        Ok(())
+    }
+}
@@ -1,7 +1,21 @@
 use super::shared::my_hash;
+use aoc_core::Solution;
 
-pub fn process(input: &str) -> u32 {
-    input.split(',').map(my_hash).sum()
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 15;
+    const PART: u8 = 1;
+    type Input<'a> = &'a str;
+    type Answer = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn solve(input: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(input.split(',').map(my_hash).sum())
+    }
 }
 
 #[cfg(test)]
@@ -9,14 +23,14 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_process() {
+    fn test_process() -> anyhow::Result<()> {
         let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
-        assert_eq!(1320, process(input));
+        assert_eq!(1320, Part1::process(input)?);
+        Ok(())
     }
 
     #[test]
-    fn test_input() {
-        let input = include_str!("../input.txt");
-        assert_eq!(518107, process(input));
+    fn test_input() -> anyhow::Result<()> {
+        aoc_core::answers::check::<Part1>()
     }
 }
@@ -11,6 +11,7 @@ use nom::{
 };
 
 use super::shared::my_hash;
+use aoc_core::{parse::parse_all, Solution};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Operation {
@@ -78,9 +79,24 @@ fn my_parser(input: &str) -> IResult<&str, Vec<Instruction>> {
     separated_list1(tag(","), alt((parse_remove, parse_set)))(input)
 }
 
-pub fn process(input: &str) -> String {
-    let (_, parsed) = my_parser(input).unwrap();
+pub struct Part2;
 
+impl Solution for Part2 {
+    const DAY: u8 = 15;
+    const PART: u8 = 2;
+    type Input<'a> = Vec<Instruction>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse_all(input, my_parser)?)
+    }
+
+    fn solve(parsed: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(focusing_power(&parsed))
+    }
+}
+
+fn focusing_power(parsed: &[Instruction]) -> usize {
     let mut boxes: Vec<Vec<String>> = vec![Vec::new(); 256];
     let mut focal_lengths: HashMap<String, usize> = HashMap::new();
 
@@ -107,7 +123,7 @@ pub fn process(input: &str) -> String {
         }
     });
 
-    let result = boxes
+    boxes
         .into_iter()
         .zip(1..)
         .fold(0, |acc, (lens_box, box_number)| {
@@ -117,8 +133,7 @@ pub fn process(input: &str) -> String {
                 .fold(0, |acc, (lens, lens_number)| {
                     acc + box_number * lens_number * focal_lengths[&lens]
                 })
-        });
-    result.to_string()
+        })
 }
 
 #[cfg(test)]
@@ -182,14 +197,35 @@ mod tests {
     }
 
     #[test]
-    fn test_process() {
+    fn test_process() -> anyhow::Result<()> {
         let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
-        assert_eq!("145", process(input));
+        assert_eq!(145, Part2::process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_input() -> anyhow::Result<()> {
+        aoc_core::answers::check::<Part2>()
+    }
+
+    /// Parsing may fail but must neither panic nor hang, also when rendering the error
+    fn fuzz_parse(input: &str) {
+        if let Err(error) = parse_all(input, my_parser) {
+            error.to_string();
+        }
+    }
+
+    #[test]
+    fn test_fuzz_bytes() {
+        bolero::check!().for_each(|bytes: &[u8]| fuzz_parse(&String::from_utf8_lossy(bytes)));
     }
 
     #[test]
-    fn test_input() {
-        let input = include_str!("../input.txt");
-        assert_eq!("303404", process(input));
+    fn test_fuzz_generated() {
+        use crate::generate::generate;
+        use aoc_core::generate::{mutate, rng};
+        bolero::check!()
+            .with_type::<(u64, Vec<(u16, u8)>)>()
+            .for_each(|(seed, edits)| fuzz_parse(&mutate(&generate(&mut rng(*seed), 5), edits)));
     }
 }
@@ -0,0 +1,38 @@
+//! Random mirror contraptions
+//!
+//! `size` is the width and height of the contraption.
+use rand::{seq::SliceRandom, Rng};
+
+/// Width and height of a real input
+pub const DEFAULT_SIZE: usize = 110;
+
+const MIRRORS: [char; 4] = ['|', '-', '/', '\\'];
+
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            input.push(if rng.gen_bool(0.1) {
+                *MIRRORS.choose(rng).expect("not empty")
+            } else {
+                '.'
+            });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1::Part1, part2::Part2};
+    use aoc_core::{generate::rng, Solution};
+
+    #[test]
+    fn test_generated_input() -> anyhow::Result<()> {
+        let input = generate(&mut rng(1), 30);
+        assert!(Part1::process(&input)? <= Part2::process(&input)?);
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
+pub mod generate;
 pub mod part1;
 pub mod part2;
 pub mod shared;
+pub mod visualize;
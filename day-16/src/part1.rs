@@ -1,37 +1,38 @@
 use super::shared::*;
-use glam::IVec2;
+use aoc_core::Solution;
+use aoc_grid::{Direction, Grid, Point};
 
-pub fn process(input: &str) -> String {
-    let mut grid = parse_into_grid(input);
-    illuminate_grid(&mut grid, (IVec2::new(0, 0), Direction::East));
-    grid.into_iter()
-        .filter(|tile| tile.illuminated)
-        .count()
-        .to_string()
+pub struct Part1;
+
+impl Solution for Part1 {
+    const DAY: u8 = 16;
+    const PART: u8 = 1;
+    type Input<'a> = Grid<Tile>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse_into_grid(input)?)
+    }
+
+    fn solve(mut grid: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        illuminate_grid(&mut grid, (Point::new(0, 0), Direction::East));
+        Ok(grid.iter().filter(|tile| tile.illuminated).count())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
+    use std::path::PathBuf;
 
-    #[test]
-    fn test_process() {
-        let input = r".|...\....
-|.-.\.....
-.....|-...
-........|.
-..........
-.........\
-..../.\\..
-.-.-/..|..
-.|....-|.\
-..//.|....";
-        assert_eq!("46", process(input));
+    #[rstest]
+    fn test_examples(#[files("examples/part1/*.txt")] path: PathBuf) -> anyhow::Result<()> {
+        aoc_core::examples::check::<Part1>(&path)
     }
 
     #[test]
-    fn test_input() {
-        let input = include_str!("../input.txt");
-        assert_eq!("7498", process(input));
+    fn test_input() -> anyhow::Result<()> {
+        aoc_core::answers::check::<Part1>()
     }
 }
@@ -1,73 +1,78 @@
 use super::shared::*;
-use glam::IVec2;
+use aoc_core::Solution;
+use aoc_grid::{Direction, Grid, Point};
 use rayon::prelude::*;
-use toodee::TooDeeOps;
 
-fn get_starting_positions(num_rows: i32, num_cols: i32) -> Vec<(IVec2, Direction)> {
+/// Every tile on the edge with the directions pointing into the grid
+pub fn get_starting_positions(num_rows: isize, num_cols: isize) -> Vec<(Point, Direction)> {
     assert!(num_cols >= 0 && num_rows >= 0);
     // convert to idx
     let max_row_idx = num_rows - 1;
     let max_col_idx = num_cols - 1;
 
     // corners
-    let mut result: Vec<(IVec2, Direction)> = vec![
-        (IVec2::new(0, 0), Direction::East),
-        (IVec2::new(0, 0), Direction::South),
-        (IVec2::new(max_col_idx, 0), Direction::West),
-        (IVec2::new(max_col_idx, 0), Direction::South),
-        (IVec2::new(0, max_row_idx), Direction::East),
-        (IVec2::new(0, max_row_idx), Direction::North),
-        (IVec2::new(max_col_idx, max_row_idx), Direction::West),
-        (IVec2::new(max_col_idx, max_row_idx), Direction::North),
+    let mut result: Vec<(Point, Direction)> = vec![
+        (Point::new(0, 0), Direction::East),
+        (Point::new(0, 0), Direction::South),
+        (Point::new(max_col_idx, 0), Direction::West),
+        (Point::new(max_col_idx, 0), Direction::South),
+        (Point::new(0, max_row_idx), Direction::East),
+        (Point::new(0, max_row_idx), Direction::North),
+        (Point::new(max_col_idx, max_row_idx), Direction::West),
+        (Point::new(max_col_idx, max_row_idx), Direction::North),
     ];
 
     // left & right edges
     for y_pos in 1..max_row_idx {
-        result.push((IVec2::new(0, y_pos), Direction::East));
-        result.push((IVec2::new(max_col_idx, y_pos), Direction::West));
+        result.push((Point::new(0, y_pos), Direction::East));
+        result.push((Point::new(max_col_idx, y_pos), Direction::West));
     }
 
     // top and bottom row
     for x_pos in 1..max_col_idx {
-        result.push((IVec2::new(x_pos, 0), Direction::South));
-        result.push((IVec2::new(x_pos, max_row_idx), Direction::North));
+        result.push((Point::new(x_pos, 0), Direction::South));
+        result.push((Point::new(x_pos, max_row_idx), Direction::North));
     }
 
     result
 }
 
-pub fn process(input: &str) -> String {
-    let grid = parse_into_grid(input);
-    get_starting_positions(grid.num_rows() as i32, grid.num_cols() as i32)
-        .into_par_iter()
-        .map(|s| illuminate_grid(&mut grid.clone(), s))
-        .max()
-        .expect("some result should exists")
-        .to_string()
+pub struct Part2;
+
+impl Solution for Part2 {
+    const DAY: u8 = 16;
+    const PART: u8 = 2;
+    type Input<'a> = Grid<Tile>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse_into_grid(input)?)
+    }
+
+    fn solve(grid: Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        Ok(
+            get_starting_positions(grid.height() as isize, grid.width() as isize)
+                .into_par_iter()
+                .map(|s| illuminate_grid(&mut grid.clone(), s))
+                .max()
+                .expect("some result should exists"),
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
+    use std::path::PathBuf;
 
-    #[test]
-    fn test_process() {
-        let input = r".|...\....
-|.-.\.....
-.....|-...
-........|.
-..........
-.........\
-..../.\\..
-.-.-/..|..
-.|....-|.\
-..//.|....";
-        assert_eq!("51", process(input));
+    #[rstest]
+    fn test_examples(#[files("examples/part2/*.txt")] path: PathBuf) -> anyhow::Result<()> {
+        aoc_core::examples::check::<Part2>(&path)
     }
 
     #[test]
-    fn test_input() {
-        let input = include_str!("../input.txt");
-        assert_eq!("7846", process(input));
+    fn test_input() -> anyhow::Result<()> {
+        aoc_core::answers::check::<Part2>()
     }
 }
@@ -1,70 +1,10 @@
-use glam::IVec2;
-use std::{
-    collections::{HashMap, VecDeque},
-    fmt::Debug,
-    ops::Add,
-};
-use toodee::{TooDee, TooDeeOps};
-
-#[derive(Debug, Clone, Copy)]
-pub enum Direction {
-    North,
-    South,
-    East,
-    West,
-}
-
-impl Add<Direction> for IVec2 {
-    type Output = IVec2;
-    fn add(self, rhs: Direction) -> Self::Output {
-        self + Into::<IVec2>::into(rhs)
-    }
-}
-
-impl TryFrom<IVec2> for Direction {
-    type Error = &'static str;
-    fn try_from(value: IVec2) -> Result<Self, Self::Error> {
-        let value = value.clamp(IVec2 { x: -1, y: -1 }, IVec2 { x: 1, y: 1 });
-        match (value.x, value.y) {
-            (0, 0) => Err("did not move"),
-            (1, 0) => Ok(Direction::East),
-            (-1, 0) => Ok(Direction::West),
-            (0, 1) => Ok(Direction::South),
-            (0, -1) => Ok(Direction::North),
-            _ => Err("move not defined"),
-        }
-    }
-}
-
-impl From<Direction> for IVec2 {
-    fn from(value: Direction) -> Self {
-        match value {
-            Direction::North => Self::new(0, -1),
-            Direction::South => Self::new(0, 1),
-            Direction::East => Self::new(1, 0),
-            Direction::West => Self::new(-1, 0),
-        }
-    }
-}
-
-impl Direction {
-    pub fn to_point(self) -> IVec2 {
-        self.into()
-    }
-
-    /// Converts from two points into a Direction
-    ///
-    /// # panics
-    /// Will panic when trying to move diagonal or if `from==to`
-    pub fn from_points(from: IVec2, to: IVec2) -> Self {
-        let movement = to - from;
-        movement.try_into().unwrap()
-    }
-}
+use aoc_core::ParseError;
+use aoc_grid::{Direction, Grid, Point};
+use std::fmt::Debug;
 
 #[derive(Clone, Copy, Default)]
 pub struct Tile {
-    pub position: IVec2,
+    pub position: Point,
     pub mirror: Option<char>,
     pub illuminated: bool,
     passed: [bool; 4],
@@ -78,7 +18,7 @@ impl Debug for Tile {
 }
 
 impl Tile {
-    pub fn new(position: IVec2, mirror: Option<char>) -> Self {
+    pub fn new(position: Point, mirror: Option<char>) -> Self {
         Self {
             position,
             mirror,
@@ -95,99 +35,71 @@ impl Tile {
     ///
     /// returns `None` if the tile was already visited by a light
     /// beam going into the same direction
-    pub fn pass(&mut self, to: Direction) -> Option<Vec<IVec2>> {
+    pub fn pass(&mut self, to: Direction) -> Option<Vec<Point>> {
         use Direction::*;
         if self.passed[to as usize] {
             return None;
         }
         self.passed[to as usize] = true;
         self.illuminated = true;
-        if self.mirror.is_none() {
-            Some(vec![self.position + to])
-        } else {
-            let mirror = self.mirror.expect("mirror is not None here");
-            match mirror {
-                '|' => match to {
-                    North | South => Some(vec![self.position + to]),
-                    East | West => Some(vec![self.position + North, self.position + South]),
-                },
-                '/' => match to {
-                    North => Some(vec![self.position + East]),
-                    South => Some(vec![self.position + West]),
-                    West => Some(vec![self.position + South]),
-                    East => Some(vec![self.position + North]),
-                },
-                '\\' => match to {
-                    North => Some(vec![self.position + West]),
-                    South => Some(vec![self.position + East]),
-                    West => Some(vec![self.position + North]),
-                    East => Some(vec![self.position + South]),
-                },
-                '-' => match to {
-                    North | South => Some(vec![self.position + East, self.position + West]),
-                    East | West => Some(vec![self.position + to]),
-                },
-                _ => unreachable!(),
-            }
-        }
+        let outgoing = match (self.mirror, to.is_vertical()) {
+            (None, _) | (Some('|'), true) | (Some('-'), false) => vec![to],
+            (Some('|'), false) => vec![North, South],
+            (Some('-'), true) => vec![East, West],
+            (Some('/'), true) | (Some('\\'), false) => vec![to.turn_right()],
+            (Some('/'), false) | (Some('\\'), true) => vec![to.turn_left()],
+            (Some(val), _) => unreachable!("found {}", val),
+        };
+        Some(outgoing.into_iter().map(|d| self.position + d).collect())
     }
 }
 
-pub fn parse_into_grid(input: &str) -> TooDee<Tile> {
-    let mut rows: usize = 0;
-    let mut cols: usize = 0;
-    let tiles: HashMap<IVec2, Tile> = input
-        .lines()
-        .enumerate()
-        .flat_map(|(line_idx, line)| {
-            rows = rows.max(line_idx + 1);
-            line.chars()
-                .enumerate()
-                .map(|(c_idx, c)| {
-                    cols = cols.max(c_idx + 1);
-                    let pos = IVec2::new(c_idx as i32, line_idx as i32);
-                    (
-                        pos,
-                        Tile::new(
-                            pos,
-                            match c {
-                                '.' => None,
-                                val => Some(val),
-                            },
-                        ),
-                    )
-                })
-                .collect::<Vec<_>>()
-        })
-        .collect();
-    let mut grid: TooDee<Tile> = TooDee::new(cols, rows);
-    for x in 0..cols {
-        for y in 0..rows {
-            grid[x][y] = *tiles.get(&IVec2::new(x as i32, y as i32)).expect("exists")
+/// A single character of the input, checked to be a mirror, splitter or `.`
+#[derive(Clone, Copy)]
+struct Cell(Option<char>);
+
+impl TryFrom<char> for Cell {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Cell(None)),
+            '|' | '-' | '/' | '\\' => Ok(Cell(Some(value))),
+            _ => Err("expected one of `.|-/\\`"),
         }
     }
-    grid
 }
 
-pub fn illuminate_grid(grid: &mut TooDee<Tile>, start: (IVec2, Direction)) -> usize {
-    let mut work_queue: VecDeque<(IVec2, Direction)> = VecDeque::new();
-    work_queue.push_back(start);
-    while !work_queue.is_empty() {
-        let (pos, to) = work_queue.pop_front().expect("not empty yet");
-        if let Some(new_positions) = grid[pos.x as usize][pos.y as usize].pass(to) {
+pub fn parse_into_grid(input: &str) -> Result<Grid<Tile>, ParseError> {
+    let grid: Grid<Cell> = Grid::parse(input)?;
+    Ok(grid.map_indexed(|c, cell| Tile::new(c.into(), cell.0)))
+}
+
+pub fn illuminate_grid(grid: &mut Grid<Tile>, start: (Point, Direction)) -> usize {
+    let mut beams = vec![start];
+    while !beams.is_empty() {
+        beams = spread(grid, &beams);
+    }
+    grid.iter().filter(|tile| tile.illuminated).count()
+}
+
+/// Moves every beam one tile further, returns where they are headed next
+///
+/// Beams leaving the grid or following one which passed before are dropped.
+pub fn spread(grid: &mut Grid<Tile>, beams: &[(Point, Direction)]) -> Vec<(Point, Direction)> {
+    let mut next = Vec::new();
+    for &(pos, to) in beams {
+        if let Some(new_positions) = grid[pos].pass(to) {
             for new_pos in new_positions.into_iter() {
-                if new_pos.x >= 0 && new_pos.y >= 0 {
-                    let x = new_pos.x as usize;
-                    let y = new_pos.y as usize;
-                    let new_direction: Direction = Direction::from_points(pos, new_pos);
-                    if x < grid.num_cols() && y < grid.num_rows() {
-                        work_queue.push_back((new_pos, new_direction))
-                    }
+                if grid.contains(new_pos) {
+                    let new_direction =
+                        Direction::between(pos, new_pos).expect("neighbouring tile");
+                    next.push((new_pos, new_direction))
                 }
             }
         }
     }
-    grid.into_iter().filter(|tile| tile.illuminated).count()
+    next
 }
 
 #[cfg(test)]
@@ -197,62 +109,62 @@ mod tests {
 
     #[fixture]
     fn tile(#[default(None)] mirror: Option<char>) -> Tile {
-        Tile::new(IVec2::new(0, 0), mirror)
+        Tile::new(Point::new(0, 0), mirror)
     }
 
     #[rstest]
-    #[case::going_north(Direction::North, Some(vec![Direction::East.to_point(), Direction::West.to_point()]))]
-    #[case::going_south(Direction::South, Some(vec![Direction::East.to_point(), Direction::West.to_point()]))]
-    #[case::going_east(Direction::East, Some(vec![Direction::East.to_point()]))]
-    #[case::going_west(Direction::West, Some(vec![Direction::West.to_point()]))]
+    #[case::going_north(Direction::North, Some(vec![Direction::East.offset(), Direction::West.offset()]))]
+    #[case::going_south(Direction::South, Some(vec![Direction::East.offset(), Direction::West.offset()]))]
+    #[case::going_east(Direction::East, Some(vec![Direction::East.offset()]))]
+    #[case::going_west(Direction::West, Some(vec![Direction::West.offset()]))]
     fn test_horizontal_mirror(
         #[case] to: Direction,
         #[with(Some('-'))] tile: Tile,
-        #[case] expected: Option<Vec<IVec2>>,
+        #[case] expected: Option<Vec<Point>>,
     ) {
-        let mut tile: Tile = tile.clone();
+        let mut tile: Tile = tile;
         assert_eq!(tile.pass(to), expected)
     }
 
     #[rstest]
-    #[case::going_north(Direction::North, Some(vec![Direction::North.to_point()]))]
-    #[case::going_south(Direction::South, Some(vec![Direction::South.to_point()]))]
-    #[case::going_east(Direction::East, Some(vec![Direction::North.to_point(), Direction::South.to_point()]))]
-    #[case::going_west(Direction::West, Some(vec![Direction::North.to_point(), Direction::South.to_point()]))]
+    #[case::going_north(Direction::North, Some(vec![Direction::North.offset()]))]
+    #[case::going_south(Direction::South, Some(vec![Direction::South.offset()]))]
+    #[case::going_east(Direction::East, Some(vec![Direction::North.offset(), Direction::South.offset()]))]
+    #[case::going_west(Direction::West, Some(vec![Direction::North.offset(), Direction::South.offset()]))]
     fn test_vertical_mirror(
         #[case] to: Direction,
         #[with(Some('|'))] tile: Tile,
-        #[case] expected: Option<Vec<IVec2>>,
+        #[case] expected: Option<Vec<Point>>,
     ) {
-        let mut tile: Tile = tile.clone();
+        let mut tile: Tile = tile;
         assert_eq!(tile.pass(to), expected)
     }
 
     #[rstest]
-    #[case::going_north(Direction::North, Some(vec![Direction::East.to_point()]))]
-    #[case::going_south(Direction::South, Some(vec![Direction::West.to_point()]))]
-    #[case::going_east(Direction::East, Some(vec![Direction::North.to_point()]))]
-    #[case::going_west(Direction::West, Some(vec![Direction::South.to_point()]))]
+    #[case::going_north(Direction::North, Some(vec![Direction::East.offset()]))]
+    #[case::going_south(Direction::South, Some(vec![Direction::West.offset()]))]
+    #[case::going_east(Direction::East, Some(vec![Direction::North.offset()]))]
+    #[case::going_west(Direction::West, Some(vec![Direction::South.offset()]))]
     fn test_slash_mirror(
         #[case] to: Direction,
         #[with(Some('/'))] tile: Tile,
-        #[case] expected: Option<Vec<IVec2>>,
+        #[case] expected: Option<Vec<Point>>,
     ) {
-        let mut tile: Tile = tile.clone();
+        let mut tile: Tile = tile;
         assert_eq!(tile.pass(to), expected)
     }
 
     #[rstest]
-    #[case::going_north(Direction::North, Some(vec![Direction::West.to_point()]))]
-    #[case::going_south(Direction::South, Some(vec![Direction::East.to_point()]))]
-    #[case::going_east(Direction::East, Some(vec![Direction::South.to_point()]))]
-    #[case::going_west(Direction::West, Some(vec![Direction::North.to_point()]))]
+    #[case::going_north(Direction::North, Some(vec![Direction::West.offset()]))]
+    #[case::going_south(Direction::South, Some(vec![Direction::East.offset()]))]
+    #[case::going_east(Direction::East, Some(vec![Direction::South.offset()]))]
+    #[case::going_west(Direction::West, Some(vec![Direction::North.offset()]))]
     fn test_backslash_mirror(
         #[case] to: Direction,
         #[with(Some('\\'))] tile: Tile,
-        #[case] expected: Option<Vec<IVec2>>,
+        #[case] expected: Option<Vec<Point>>,
     ) {
-        let mut tile: Tile = tile.clone();
+        let mut tile: Tile = tile;
         assert_eq!(tile.pass(to), expected)
     }
 
@@ -265,4 +177,11 @@ mod tests {
         // this is a sanity check since the order will matter for the future
         assert_eq!(direction as usize, expected)
     }
+
+    #[test]
+    fn test_invalid_tile() {
+        let err = parse_into_grid("..\n.x").expect_err("invalid input");
+        let location = err.location.expect("has location");
+        assert_eq!((location.line, location.column), (2, 2));
+    }
 }
@@ -0,0 +1,108 @@
+//! The beam of part 1 spreading through the contraption, one frame per tile it moves,
+//! and a heatmap of all the beams of part 2
+use crate::part2::get_starting_positions;
+use crate::shared::{illuminate_grid, parse_into_grid, spread, Tile};
+use aoc_core::ParseError;
+use aoc_grid::{
+    render::{Cell, Frame, Frames, Rgb},
+    Direction, Grid, Point,
+};
+
+const ENERGIZED: Rgb = Rgb(90, 60, 0);
+const COLD: Rgb = Rgb(40, 0, 80);
+
+pub fn frames(input: &str) -> Result<Frames, ParseError> {
+    let mut grid = parse_into_grid(input)?;
+    let mut beams = vec![(Point::new(0, 0), Direction::East)];
+    let mut done = false;
+    Ok(Box::new(std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        // the last frame shows the energized tiles without beams
+        let frame = render(&grid, &beams);
+        done = beams.is_empty();
+        beams = spread(&mut grid, &beams);
+        Some(frame)
+    })))
+}
+
+/// How many of the beams of part 2 energize each tile, from purple over red to yellow
+pub fn heatmap(input: &str, _steps: usize) -> Result<Frame, ParseError> {
+    let grid = parse_into_grid(input)?;
+    let mut heat = grid.map(|_| 0_usize);
+    for start in get_starting_positions(grid.height() as isize, grid.width() as isize) {
+        let mut grid = grid.clone();
+        illuminate_grid(&mut grid, start);
+        for (heat, tile) in heat.iter_mut().zip(grid.iter()) {
+            *heat += usize::from(tile.illuminated);
+        }
+    }
+    let hottest = heat.iter().copied().max().unwrap_or_default().max(1);
+    Ok(grid.map_indexed(|position, tile| {
+        let cell = Cell::new(tile.mirror.unwrap_or(' '), Rgb::WHITE);
+        match heat[position] {
+            0 => cell,
+            beams => {
+                let amount = beams as f32 / hottest as f32;
+                if amount < 0.5 {
+                    cell.on(COLD.mix(Rgb::RED, 2.0 * amount))
+                } else {
+                    cell.on(Rgb::RED.mix(Rgb::YELLOW, 2.0 * amount - 1.0))
+                }
+            }
+        }
+    }))
+}
+
+/// Mirrors in white on the energized tiles, the heads of the beams as arrows
+fn render(grid: &Grid<Tile>, beams: &[(Point, Direction)]) -> Frame {
+    let mut frame = grid.map(|tile| {
+        let cell = match tile.mirror {
+            Some(mirror) => Cell::new(mirror, Rgb::WHITE),
+            None => Cell::new('.', Rgb::GREY),
+        };
+        if tile.illuminated {
+            cell.on(ENERGIZED)
+        } else {
+            cell
+        }
+    });
+    for &(position, to) in beams {
+        let glyph = match to {
+            Direction::North => '^',
+            Direction::South => 'v',
+            Direction::East => '>',
+            Direction::West => '<',
+        };
+        frame[position] = Cell::new(glyph, Rgb::BLACK).on(Rgb::YELLOW);
+    }
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_grid::image;
+
+    #[test]
+    fn test_frames() -> anyhow::Result<()> {
+        let input = std::fs::read_to_string("examples/part1/1.txt")?;
+        let frames: Vec<Frame> = frames(&input)?.collect();
+        let last = frames.last().expect("at least one frame");
+        assert_eq!(46, last.iter().filter(|cell| cell.bg.is_some()).count());
+        assert_eq!(
+            Cell::new('>', Rgb::BLACK).on(Rgb::YELLOW),
+            frames[0][Point::new(0, 0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_heatmap() -> anyhow::Result<()> {
+        let input = std::fs::read_to_string("examples/part1/1.txt")?;
+        let heatmap = heatmap(&input, 0)?;
+        assert!(heatmap.iter().all(|cell| cell.bg.is_some()));
+        aoc_core::snapshot::check("snapshots/heatmap.svg", image::svg(&heatmap, 10))
+    }
+}